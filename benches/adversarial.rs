@@ -0,0 +1,87 @@
+use std::hash::BuildHasher;
+use std::hint::black_box;
+use std::time::Duration;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use hashbrown::HashSet;
+use rand::prelude::*;
+
+const NUM_KEYS: usize = 2000;
+
+// Hash tables with up to 2^COLLIDING_BITS buckets index them with the low bits
+// of the hash, so keys agreeing on these bits all map to the same home bucket
+// and degrade every operation to a linear probe.
+const COLLIDING_BITS: u32 = 16;
+const SEED: u64 = 42;
+
+/// Constructs `n` distinct u64 keys whose hashes under the given (known) state
+/// all share the same low `COLLIDING_BITS` bits.
+///
+/// foldhash makes no claims of DoS resistance against an attacker that knows
+/// the seed, and with it a brute-force search is all that's needed: a random
+/// key matches with probability 2^-COLLIDING_BITS, so this takes on the order
+/// of n * 2^COLLIDING_BITS hash evaluations.
+fn colliding_keys<S: BuildHasher>(state: &S, n: usize) -> Vec<u64> {
+    let mask = (1u64 << COLLIDING_BITS) - 1;
+    let target = state.hash_one(0u64) & mask;
+    (0u64..)
+        .filter(|k| state.hash_one(k) & mask == target)
+        .take(n)
+        .collect()
+}
+
+fn profile_lookup<S: BuildHasher>(
+    c: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    state: S,
+    keys: &[u64],
+) {
+    let mut set = HashSet::with_hasher(state);
+    set.extend(keys.iter().copied());
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            for k in keys {
+                black_box(set.contains(black_box(k)));
+            }
+        })
+    });
+}
+
+fn bench_adversarial(c: &mut Criterion) {
+    let fast = foldhash::fast::FixedState::with_seed(SEED);
+    let quality = foldhash::quality::FixedState::with_seed(SEED);
+
+    let mut rng = StdRng::seed_from_u64(0x123456789abcdef);
+    let random: Vec<u64> = (0..NUM_KEYS).map(|_| rng.gen()).collect();
+    let attack_fast = colliding_keys(&fast, NUM_KEYS);
+    let attack_quality = colliding_keys(&quality, NUM_KEYS);
+
+    let mut g = c.benchmark_group("Adversarial");
+    g.sampling_mode(criterion::SamplingMode::Flat);
+    profile_lookup(&mut g, "lookup-random-foldhash-fast", fast, &random);
+    profile_lookup(&mut g, "lookup-colliding-foldhash-fast", fast, &attack_fast);
+    profile_lookup(&mut g, "lookup-random-foldhash-quality", quality, &random);
+    profile_lookup(
+        &mut g,
+        "lookup-colliding-foldhash-quality",
+        quality,
+        &attack_quality,
+    );
+
+    // Keys attacking one seed are harmless for a different (e.g. random) seed.
+    let other = foldhash::fast::FixedState::with_seed(SEED + 1);
+    profile_lookup(
+        &mut g,
+        "lookup-colliding-foldhash-fast-other-seed",
+        other,
+        &attack_fast,
+    );
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().measurement_time(Duration::from_secs(5));
+    targets = bench_adversarial
+);
+criterion_main!(benches);
@@ -2,9 +2,18 @@ use std::hash::BuildHasher;
 
 use rand::prelude::*;
 
+/// Where the base values of the avalanche test come from.
+#[derive(Clone, Copy)]
+enum Inputs {
+    Random,
+    // Consecutive integers from a random start, like auto-increment IDs.
+    Sequential,
+}
+
 fn compute_u64_avalanche<H: BuildHasher, F: FnMut() -> H>(
     num_hashers: usize,
     iters_per_hasher: usize,
+    inputs: Inputs,
     mut new_hasher: F,
 ) -> Vec<f64> {
     let mut rng = thread_rng();
@@ -12,8 +21,12 @@ fn compute_u64_avalanche<H: BuildHasher, F: FnMut() -> H>(
     for _ in 0..num_hashers {
         let h = new_hasher();
         let mut bit_flips = vec![0; 64 * 64];
-        for _ in 0..iters_per_hasher {
-            let base_val: u64 = rng.gen();
+        let start: u64 = rng.gen::<u32>() as u64;
+        for i in 0..iters_per_hasher {
+            let base_val: u64 = match inputs {
+                Inputs::Random => rng.gen(),
+                Inputs::Sequential => start + i as u64,
+            };
             let base_hash = h.hash_one(base_val);
             for flip_pos in 0..64 {
                 let delta_val = base_val ^ (1 << flip_pos);
@@ -37,9 +50,13 @@ fn compute_u64_avalanche<H: BuildHasher, F: FnMut() -> H>(
     worst_bias
 }
 
-fn write_avalanche_csv<H: BuildHasher, F: FnMut() -> H>(name: &str, new_hasher: F) {
+fn write_avalanche_csv<H: BuildHasher, F: FnMut() -> H>(name: &str, inputs: Inputs, new_hasher: F) {
+    let name = match inputs {
+        Inputs::Random => name.to_string(),
+        Inputs::Sequential => format!("seq-{name}"),
+    };
     println!("calculating avalanche properties of {name}");
-    let strings: Vec<String> = compute_u64_avalanche(10000, 1000, new_hasher)
+    let strings: Vec<String> = compute_u64_avalanche(10000, 1000, inputs, new_hasher)
         .into_iter()
         .map(|b| format!("{b}"))
         .collect();
@@ -48,11 +65,17 @@ fn write_avalanche_csv<H: BuildHasher, F: FnMut() -> H>(name: &str, new_hasher:
 }
 
 fn main() {
-    write_avalanche_csv("foldhash-fast", || foldhash::fast::RandomState::default());
-    write_avalanche_csv("foldhash-quality", || {
-        foldhash::quality::RandomState::default()
-    });
-    write_avalanche_csv("siphash", || std::hash::RandomState::default());
-    write_avalanche_csv("ahash", || ahash::RandomState::default());
-    write_avalanche_csv("fxhash", || fxhash::FxBuildHasher::default());
+    for inputs in [Inputs::Random, Inputs::Sequential] {
+        write_avalanche_csv(
+            "foldhash-fast",
+            inputs,
+            foldhash::fast::RandomState::default,
+        );
+        write_avalanche_csv("foldhash-quality", inputs, || {
+            foldhash::quality::RandomState::default()
+        });
+        write_avalanche_csv("siphash", inputs, std::hash::RandomState::default);
+        write_avalanche_csv("ahash", inputs, ahash::RandomState::default);
+        write_avalanche_csv("fxhash", inputs, fxhash::FxBuildHasher::default);
+    }
 }
@@ -2,7 +2,7 @@ use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
 use rand::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::hash::BuildHasher;
+use std::hash::{BuildHasher, Hasher};
 use std::hint::black_box;
 use std::time::Duration;
 
@@ -12,9 +12,20 @@ mod distribution;
 use distribution::Distribution;
 
 fn profile_hashonly<S: BuildHasher + Default, D: Distribution>(
+    hash_name: &str,
+    distr: D,
+    c: &mut BenchmarkGroup<'_, WallTime>,
+) {
+    profile_hashonly_with::<S, D>(hash_name, distr, c, |hasher, x| hasher.hash_one(x));
+}
+
+/// Like `profile_hashonly`, but hashing with the given function, e.g. one of
+/// the specialized entry points of a hasher.
+fn profile_hashonly_with<S: Default, D: Distribution>(
     hash_name: &str,
     mut distr: D,
     c: &mut BenchmarkGroup<'_, WallTime>,
+    hash: impl Fn(&S, &D::Value) -> u64,
 ) {
     let name = format!("hashonly-{}-{hash_name}", distr.name().to_lowercase());
     let mut rng = StdRng::seed_from_u64(0x123456789abcdef);
@@ -30,7 +41,7 @@ fn profile_hashonly<S: BuildHasher + Default, D: Distribution>(
             );
             let start = std::time::Instant::now();
             for i in 0..iters as usize {
-                black_box(hasher.hash_one(&to_hash[i % NUM_PRECOMPUTED_KEYS]));
+                black_box(hash(&hasher, &to_hash[i % NUM_PRECOMPUTED_KEYS]));
             }
             start.elapsed()
         });
@@ -121,7 +132,7 @@ fn profile_set_build<S: BuildHasher + Default, D: Distribution>(
         b.iter_custom(|iters| {
             // Repeat each key 10 times.
             let keys: Vec<_> = (0..map_size).map(|_| distr.sample(&mut rng)).collect();
-            let mut keys: Vec<_> = keys.iter().cycle().cloned().take(10 * map_size).collect();
+            let mut keys: Vec<_> = keys.iter().cycle().take(10 * map_size).cloned().collect();
             keys.shuffle(&mut rng);
             let keys = black_box(keys);
 
@@ -174,15 +185,19 @@ fn bench_hashes(c: &mut Criterion) {
     let map_size = 1000;
     profile_distr(distribution::U32, map_size, c);
     profile_distr(distribution::U64, map_size, c);
+    profile_distr(distribution::SequentialU64::default(), map_size, c);
     profile_distr(distribution::U64LoBits, map_size, c);
     profile_distr(distribution::U64HiBits, map_size, c);
+    profile_distr(distribution::AsciiChar, map_size, c);
     profile_distr(distribution::U32Pair, map_size, c);
     profile_distr(distribution::U64Pair, map_size, c);
+    profile_distr(distribution::FixedVec8, map_size, c);
     profile_distr(distribution::Rgba, map_size, c);
     profile_distr(distribution::Ipv4, map_size, c);
     profile_distr(distribution::Ipv6, map_size, c);
     profile_distr(distribution::StrUuid, map_size, c);
     profile_distr(distribution::StrDate, map_size, c);
+    profile_distr(distribution::StrNumeric, map_size, c);
     profile_distr(distribution::AccessLog, map_size, c);
     profile_distr(distribution::StrWordList::english(), map_size, c);
     profile_distr(distribution::StrWordList::urls(), map_size, c);
@@ -190,9 +205,97 @@ fn bench_hashes(c: &mut Criterion) {
     profile_distr(distribution::TenKilobyte, map_size, c);
 }
 
+#[rustfmt::skip]
+fn profile_specialized(c: &mut Criterion) {
+    use foldhash::fast::RandomState;
+
+    let c = &mut c.benchmark_group("Specialized");
+    c.sampling_mode(criterion::SamplingMode::Flat);
+
+    let distr = distribution::U64;
+    profile_hashonly_with::<RandomState, _>("foldhash-fast-hash_one", distr.clone(), c, |s, x| s.hash_one(x));
+    profile_hashonly_with::<RandomState, _>("foldhash-fast-hash_primitive", distr.clone(), c, |s, x| s.hash_primitive(*x));
+
+    let distr = distribution::Timeout;
+    profile_hashonly_with::<RandomState, _>("foldhash-fast-hash_one", distr.clone(), c, |s, x| s.hash_one(x));
+    profile_hashonly_with::<RandomState, _>("foldhash-fast-hash_duration", distr.clone(), c, |s, x| s.hash_duration(*x));
+}
+
+fn profile_hash_array(c: &mut Criterion) {
+    let c = &mut c.benchmark_group("Array16");
+    c.sampling_mode(criterion::SamplingMode::Flat);
+    let mut rng = StdRng::seed_from_u64(0x123456789abcdef);
+    let hasher = foldhash::fast::RandomState::default();
+
+    let arrays: Vec<[u8; 16]> = black_box((0..NUM_PRECOMPUTED_KEYS).map(|_| rng.gen()).collect());
+    let vecs: Vec<Vec<u8>> = black_box(arrays.iter().map(|a| a.to_vec()).collect());
+
+    c.bench_function("hashonly-array16-foldhash-fast-hash_array", |b| {
+        b.iter_custom(|iters| {
+            let start = std::time::Instant::now();
+            for i in 0..iters as usize {
+                black_box(hasher.hash_array(&arrays[i % NUM_PRECOMPUTED_KEYS]));
+            }
+            start.elapsed()
+        });
+    });
+
+    c.bench_function("hashonly-array16-foldhash-fast-hash_one_vec", |b| {
+        b.iter_custom(|iters| {
+            let start = std::time::Instant::now();
+            for i in 0..iters as usize {
+                black_box(hasher.hash_one(&vecs[i % NUM_PRECOMPUTED_KEYS]));
+            }
+            start.elapsed()
+        });
+    });
+}
+
+/// Feeds many in-flight hashers round-robin, where the size of the hasher
+/// state determines how many fit in cache.
+fn profile_in_flight<S: BuildHasher + Default>(
+    hash_name: &str,
+    c: &mut BenchmarkGroup<'_, WallTime>,
+) where
+    S::Hasher: Clone,
+{
+    let name = format!("inflight-u64x16-{hash_name}");
+    let mut rng = StdRng::seed_from_u64(0x123456789abcdef);
+    let state = S::default();
+    let values: Vec<u64> = black_box((0..NUM_PRECOMPUTED_KEYS).map(|_| rng.gen()).collect());
+
+    c.bench_function(&name, |b| {
+        b.iter_custom(|iters| {
+            let start = std::time::Instant::now();
+            for _ in 0..iters {
+                let mut hashers = vec![state.build_hasher(); 4 * NUM_PRECOMPUTED_KEYS];
+                for round in 0..16 {
+                    for (i, hasher) in hashers.iter_mut().enumerate() {
+                        hasher.write_u64(values[(i + round) % NUM_PRECOMPUTED_KEYS]);
+                    }
+                }
+                black_box(
+                    hashers
+                        .iter()
+                        .map(|h| h.finish())
+                        .fold(0, u64::wrapping_add),
+                );
+            }
+            start.elapsed()
+        });
+    });
+}
+
+fn profile_compact(c: &mut Criterion) {
+    let c = &mut c.benchmark_group("InFlight");
+    c.sampling_mode(criterion::SamplingMode::Flat);
+    profile_in_flight::<foldhash::fast::RandomState>("foldhash-fast", c);
+    profile_in_flight::<foldhash::fast::CompactState>("foldhash-fast-compact", c);
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default().measurement_time(Duration::from_secs(5));
-    targets = bench_hashes
+    targets = bench_hashes, profile_specialized, profile_hash_array, profile_compact
 );
 criterion_main!(benches);
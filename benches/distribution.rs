@@ -7,10 +7,10 @@ use uuid::Uuid;
 
 // 10,000 URL subsample from
 // https://github.com/ada-url/url-various-datasets/blob/main/top100/top100.txt
-static RAW_URLS: &'static str = include_str!("urls-10000.txt");
+static RAW_URLS: &str = include_str!("urls-10000.txt");
 
 // https://github.com/first20hours/google-10000-english/blob/master/google-10000-english.txt
-static RAW_ENGLISH_WORDS: &'static str = include_str!("google-10000-english.txt");
+static RAW_ENGLISH_WORDS: &str = include_str!("google-10000-english.txt");
 
 pub trait Distribution: Clone {
     type Value: Hash + Eq + Clone + std::fmt::Debug;
@@ -61,6 +61,15 @@ new_distribution!(
     rng.gen::<u16>() as u64
 );
 
+// Printable ASCII, as commonly seen by character-keyed tokenizers.
+new_distribution!(
+    AsciiChar,
+    char,
+    rng,
+    (rng.gen_range(0x20u8..0x7f) | 1) as char,
+    (rng.gen_range(0x20u8..0x7f) & !1) as char
+);
+
 new_distribution!(
     U32Pair,
     (u32, u32),
@@ -77,6 +86,32 @@ new_distribution!(
     (rng.gen(), rng.gen::<u64>() & !1)
 );
 
+// Small fixed-size integer vectors, like feature vectors or grid coordinates.
+new_distribution!(
+    FixedVec8,
+    [u32; 8],
+    rng,
+    {
+        let mut v: [u32; 8] = rng.gen();
+        v[0] |= 1;
+        v
+    },
+    {
+        let mut v: [u32; 8] = rng.gen();
+        v[0] &= !1;
+        v
+    }
+);
+
+// Timeouts of up to an hour with a random sub-second part.
+new_distribution!(
+    Timeout,
+    std::time::Duration,
+    rng,
+    std::time::Duration::new(rng.gen_range(0..3600), rng.gen_range(0..1_000_000_000) | 1),
+    std::time::Duration::new(rng.gen_range(0..3600), rng.gen_range(0..1_000_000_000) & !1)
+);
+
 new_distribution!(
     Rgba,
     (u8, u8, u8, u8),
@@ -135,6 +170,32 @@ new_distribution!(
     sample_date(rng, true)
 );
 
+// Decimal IDs and counters of 1 to 10 digits, as found in CSV columns and
+// query strings. These share most of their bytes, which stresses the
+// short-string path.
+fn sample_numeric<R: Rng>(rng: &mut R, missing: bool) -> String {
+    let digits = rng.gen_range(1..=10);
+    let lo = if digits == 1 {
+        0
+    } else {
+        10u64.pow(digits - 1)
+    };
+    let n = rng.gen_range(lo..10u64.pow(digits));
+    if missing {
+        (n & !1).to_string()
+    } else {
+        (n | 1).to_string()
+    }
+}
+
+new_distribution!(
+    StrNumeric,
+    String,
+    rng,
+    sample_numeric(rng, false),
+    sample_numeric(rng, true)
+);
+
 new_distribution!(
     Kilobyte,
     Vec<u8>,
@@ -180,6 +241,34 @@ impl Distribution for AccessLog {
     }
 }
 
+// Auto-increment IDs as commonly used for database keys. Missing keys are IDs
+// from before the start, e.g. of rows deleted long ago.
+#[derive(Default, Clone)]
+pub struct SequentialU64 {
+    next: u64,
+}
+
+impl SequentialU64 {
+    const START: u64 = 1_000_000;
+}
+
+impl Distribution for SequentialU64 {
+    type Value = u64;
+
+    fn name(&self) -> &str {
+        "SequentialU64"
+    }
+
+    fn sample<R: Rng>(&mut self, _rng: &mut R) -> Self::Value {
+        self.next += 1;
+        Self::START + self.next
+    }
+
+    fn sample_missing<R: Rng>(&mut self, rng: &mut R) -> Self::Value {
+        rng.gen_range(0..Self::START)
+    }
+}
+
 #[derive(Clone)]
 pub struct StrWordList {
     name: String,
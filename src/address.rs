@@ -0,0 +1,103 @@
+use core::hash::{Hash, Hasher};
+
+use crate::{folded_multiply, ARBITRARY20};
+
+/// A pointer-like type whose address can be used as its identity, see
+/// [`ByAddress`].
+pub trait Address {
+    /// Returns the address this pointer points to. For wide pointers only
+    /// the data address is used.
+    fn address(&self) -> *const ();
+}
+
+impl<T: ?Sized> Address for *const T {
+    #[inline(always)]
+    fn address(&self) -> *const () {
+        *self as *const ()
+    }
+}
+
+impl<T: ?Sized> Address for *mut T {
+    #[inline(always)]
+    fn address(&self) -> *const () {
+        *self as *const ()
+    }
+}
+
+impl<T: ?Sized> Address for &T {
+    #[inline(always)]
+    fn address(&self) -> *const () {
+        *self as *const T as *const ()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Address for alloc::boxed::Box<T> {
+    #[inline(always)]
+    fn address(&self) -> *const () {
+        &**self as *const T as *const ()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Address for alloc::rc::Rc<T> {
+    #[inline(always)]
+    fn address(&self) -> *const () {
+        alloc::rc::Rc::as_ptr(self) as *const ()
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: ?Sized> Address for alloc::sync::Arc<T> {
+    #[inline(always)]
+    fn address(&self) -> *const () {
+        alloc::sync::Arc::as_ptr(self) as *const ()
+    }
+}
+
+/// A wrapper which hashes and compares a pointer by the address it points to,
+/// rather than by the value behind it.
+///
+/// This is useful for maps keyed by the identity of interned or shared values.
+/// Addresses from an allocator are highly regular: their low bits are zero due
+/// to alignment, and consecutive allocations advance in fixed strides. A single
+/// folded multiply in the fast hasher doesn't break up that pattern for every
+/// seed, so the address is premixed with a multiply by a constant before it is
+/// written to the hasher. With that, addresses spread evenly over the buckets
+/// of a hash map.
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use std::rc::Rc;
+/// use foldhash::ByAddress;
+///
+/// let a = Rc::new("same");
+/// let b = Rc::new("same");
+/// let mut set = HashSet::with_hasher(foldhash::fast::RandomState::default());
+/// set.insert(ByAddress(a.clone()));
+/// assert!(set.contains(&ByAddress(a)));
+/// assert!(!set.contains(&ByAddress(b)));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(transparent)]
+pub struct ByAddress<P>(pub P);
+
+impl<P: Address> Hash for ByAddress<P> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Premix the address, see the type documentation.
+        state.write_u64(folded_multiply(
+            self.0.address() as usize as u64,
+            ARBITRARY20,
+        ));
+    }
+}
+
+impl<P: Address> PartialEq for ByAddress<P> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.address() == other.0.address()
+    }
+}
+
+impl<P: Address> Eq for ByAddress<P> {}
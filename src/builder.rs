@@ -0,0 +1,117 @@
+use core::hash::BuildHasher;
+
+use crate::fast::FoldHasher;
+use crate::{fast, quality, Finalize, FinalizeState, GlobalDefault};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A [`BuildHasher`] provided by foldhash, with introspection of how it is
+/// seeded.
+///
+/// Generic code can use this to refuse deterministic hashers for untrusted
+/// input, which is vulnerable to HashDoS attacks. It is only implemented for
+/// foldhash's own builders, and is sealed so it can't be implemented outside
+/// of foldhash.
+///
+/// ```rust
+/// use foldhash::{FastFinalize, FinalizeState, FoldBuildHasher};
+/// use foldhash::fast::{FixedState, RandomState, SeedableRandomState};
+///
+/// fn assert_randomized<S: FoldBuildHasher>(state: &S) {
+///     assert!(!state.is_deterministic(), "untrusted input needs a randomized hasher");
+/// }
+///
+/// assert_randomized(&RandomState::default());
+/// assert_randomized(&SeedableRandomState::default());
+/// assert!(FixedState::with_seed(42).is_deterministic());
+/// assert!(SeedableRandomState::with_seed(42, [1; 4]).is_deterministic());
+/// assert_randomized(&FinalizeState::<_, FastFinalize>::new(RandomState::default()));
+/// ```
+pub trait FoldBuildHasher: BuildHasher + sealed::Sealed {
+    /// Returns whether this builder's hashes are fixed by the program itself,
+    /// i.e. the same in every run.
+    ///
+    /// This is `true` for the `FixedState`s and [`fast::ConstState`]. It is
+    /// `false` for builders seeded at runtime: the `RandomState`s,
+    /// [`fast::CompactState`], [`GlobalDefault`] and
+    /// [`fast::RunReproducibleState`], whose seeds are assumed to be secret. A
+    /// [`fast::SeedableRandomState`] is deterministic if it was created from
+    /// caller-provided seeds, with
+    /// [`with_seed`](fast::SeedableRandomState::with_seed) or
+    /// [`from_ahash_keys`](fast::SeedableRandomState::from_ahash_keys), and
+    /// not if it was randomly initialized. The wrappers [`FinalizeState`] and
+    /// `CollisionTrackingState` are deterministic if the state they wrap is.
+    fn is_deterministic(&self) -> bool;
+}
+
+macro_rules! impl_fold_build_hasher {
+    ($deterministic:literal => $($t:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl FoldBuildHasher for $t {
+                #[inline(always)]
+                fn is_deterministic(&self) -> bool {
+                    $deterministic
+                }
+            }
+        )*
+    };
+}
+
+impl_fold_build_hasher! {
+    false =>
+    fast::RandomState,
+    fast::RunReproducibleState,
+    fast::CompactState,
+    GlobalDefault,
+    quality::RandomState,
+}
+
+impl_fold_build_hasher! {
+    true =>
+    fast::FixedState,
+    quality::FixedState,
+    &fast::FixedState,
+    &quality::FixedState,
+}
+
+impl<const SEED: u64> sealed::Sealed for fast::ConstState<SEED> {}
+
+impl<const SEED: u64> FoldBuildHasher for fast::ConstState<SEED> {
+    #[inline(always)]
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+}
+
+impl sealed::Sealed for fast::SeedableRandomState {}
+
+impl FoldBuildHasher for fast::SeedableRandomState {
+    #[inline(always)]
+    fn is_deterministic(&self) -> bool {
+        self.is_explicitly_seeded()
+    }
+}
+
+impl<S: FoldBuildHasher<Hasher = FoldHasher>, F: Finalize> sealed::Sealed for FinalizeState<S, F> {}
+
+impl<S: FoldBuildHasher<Hasher = FoldHasher>, F: Finalize> FoldBuildHasher for FinalizeState<S, F> {
+    #[inline(always)]
+    fn is_deterministic(&self) -> bool {
+        self.inner().is_deterministic()
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<S: FoldBuildHasher> sealed::Sealed for crate::CollisionTrackingState<S> {}
+
+#[cfg(feature = "diagnostics")]
+impl<S: FoldBuildHasher> FoldBuildHasher for crate::CollisionTrackingState<S> {
+    #[inline(always)]
+    fn is_deterministic(&self) -> bool {
+        self.inner().is_deterministic()
+    }
+}
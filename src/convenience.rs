@@ -1,10 +1,16 @@
-use super::fast::{FixedState, RandomState};
+use super::{fast, quality, DefaultState};
 
-/// Type alias for [`std::collections::HashMap<K, V, foldhash::fast::RandomState>`].
-pub type HashMap<K, V> = std::collections::HashMap<K, V, RandomState>;
+/// Type alias for [`std::collections::HashMap<K, V, foldhash::DefaultState>`].
+///
+/// This uses [`fast::RandomState`], or [`quality::RandomState`] with the
+/// `"default-quality"` feature.
+pub type HashMap<K, V> = std::collections::HashMap<K, V, DefaultState>;
 
-/// Type alias for [`std::collections::HashSet<T, foldhash::fast::RandomState>`].
-pub type HashSet<T> = std::collections::HashSet<T, RandomState>;
+/// Type alias for [`std::collections::HashSet<T, foldhash::DefaultState>`].
+///
+/// This uses [`fast::RandomState`], or [`quality::RandomState`] with the
+/// `"default-quality"` feature.
+pub type HashSet<T> = std::collections::HashSet<T, DefaultState>;
 
 /// A convenience extension trait to enable [`HashMap::new`] for hash maps that use `foldhash`.
 pub trait HashMapExt {
@@ -15,25 +21,28 @@ pub trait HashMapExt {
     fn with_capacity(capacity: usize) -> Self;
 }
 
-impl<K, V> HashMapExt for std::collections::HashMap<K, V, RandomState> {
-    fn new() -> Self {
-        Self::with_hasher(RandomState::default())
-    }
+macro_rules! impl_map_ext {
+    ($($state:ty),*) => {
+        $(
+            impl<K, V> HashMapExt for std::collections::HashMap<K, V, $state> {
+                fn new() -> Self {
+                    Self::with_hasher(<$state>::default())
+                }
 
-    fn with_capacity(capacity: usize) -> Self {
-        Self::with_capacity_and_hasher(capacity, RandomState::default())
-    }
+                fn with_capacity(capacity: usize) -> Self {
+                    Self::with_capacity_and_hasher(capacity, <$state>::default())
+                }
+            }
+        )*
+    };
 }
 
-impl<K, V> HashMapExt for std::collections::HashMap<K, V, FixedState> {
-    fn new() -> Self {
-        Self::with_hasher(FixedState::default())
-    }
-
-    fn with_capacity(capacity: usize) -> Self {
-        Self::with_capacity_and_hasher(capacity, FixedState::default())
-    }
-}
+impl_map_ext!(
+    fast::RandomState,
+    fast::FixedState,
+    quality::RandomState,
+    quality::FixedState
+);
 
 /// A convenience extension trait to enable [`HashSet::new`] for hash sets that use `foldhash`.
 pub trait HashSetExt {
@@ -44,22 +53,25 @@ pub trait HashSetExt {
     fn with_capacity(capacity: usize) -> Self;
 }
 
-impl<T> HashSetExt for std::collections::HashSet<T, RandomState> {
-    fn new() -> Self {
-        Self::with_hasher(RandomState::default())
-    }
+macro_rules! impl_set_ext {
+    ($($state:ty),*) => {
+        $(
+            impl<T> HashSetExt for std::collections::HashSet<T, $state> {
+                fn new() -> Self {
+                    Self::with_hasher(<$state>::default())
+                }
 
-    fn with_capacity(capacity: usize) -> Self {
-        Self::with_capacity_and_hasher(capacity, RandomState::default())
-    }
+                fn with_capacity(capacity: usize) -> Self {
+                    Self::with_capacity_and_hasher(capacity, <$state>::default())
+                }
+            }
+        )*
+    };
 }
 
-impl<T> HashSetExt for std::collections::HashSet<T, FixedState> {
-    fn new() -> Self {
-        Self::with_hasher(FixedState::default())
-    }
-
-    fn with_capacity(capacity: usize) -> Self {
-        Self::with_capacity_and_hasher(capacity, FixedState::default())
-    }
-}
+impl_set_ext!(
+    fast::RandomState,
+    fast::FixedState,
+    quality::RandomState,
+    quality::FixedState
+);
@@ -0,0 +1,161 @@
+use core::hash::{BuildHasher, Hasher};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use crate::fast::{FixedState, FoldHasher, SeedableRandomState};
+
+#[derive(Default)]
+struct Tracker {
+    // Every distinct key seen, as its hash and its digest.
+    keys: HashSet<(u64, u64), FixedState>,
+    // Every distinct hash value seen.
+    hashes: HashSet<u64, FixedState>,
+    collisions: u64,
+}
+
+/// A [`BuildHasher`] wrapper which counts distinct keys whose hashes collide.
+///
+/// Besides feeding the inner hasher, every hasher built by this state feeds
+/// the same writes into a second hasher with unrelated, random seeds, whose
+/// result serves as a digest of the key. A finished hash is only counted once
+/// per distinct digest, so hashing the same key again, as hash maps do on
+/// lookups and when resizing, doesn't count as a collision. This makes it
+/// suitable for wrapping the state of a real, slow hash map.
+///
+/// The counters are shared by all clones of the state. Memory use grows with
+/// the number of distinct keys, by about as much as a `HashSet` of them
+/// would take.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use foldhash::CollisionTrackingState;
+/// use foldhash::fast::FixedState;
+///
+/// let state = CollisionTrackingState::new(FixedState::with_seed(42));
+/// let mut map = HashMap::with_hasher(state.clone());
+/// for key in 0..1000u64 {
+///     map.insert(key, key);
+/// }
+/// for key in 0..1000u64 {
+///     assert_eq!(map[&key], key);
+/// }
+/// assert_eq!(state.distinct_keys(), 1000);
+/// assert_eq!(state.colliding_keys(), 0);
+/// ```
+#[derive(Clone)]
+pub struct CollisionTrackingState<S> {
+    inner: S,
+    digest: SeedableRandomState,
+    tracker: Arc<Mutex<Tracker>>,
+}
+
+impl<S> CollisionTrackingState<S> {
+    /// Creates a [`CollisionTrackingState`] wrapping the given state.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            digest: crate::testing::shuffled_state(),
+            tracker: Arc::default(),
+        }
+    }
+
+    /// Returns the number of distinct keys hashed so far.
+    pub fn distinct_keys(&self) -> u64 {
+        self.tracker.lock().unwrap().keys.len() as u64
+    }
+
+    /// Returns the number of distinct keys whose hash was equal to the hash
+    /// of a different key seen before.
+    pub fn colliding_keys(&self) -> u64 {
+        self.tracker.lock().unwrap().collisions
+    }
+
+    /// Returns the wrapped state.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: Default> Default for CollisionTrackingState<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: BuildHasher> BuildHasher for CollisionTrackingState<S> {
+    type Hasher = CollisionTrackingHasher<S::Hasher>;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> Self::Hasher {
+        CollisionTrackingHasher {
+            inner: self.inner.build_hasher(),
+            digest: self.digest.build_hasher(),
+            tracker: self.tracker.clone(),
+        }
+    }
+}
+
+/// The [`Hasher`] built by a [`CollisionTrackingState`].
+#[derive(Clone)]
+pub struct CollisionTrackingHasher<H> {
+    inner: H,
+    digest: FoldHasher,
+    tracker: Arc<Mutex<Tracker>>,
+}
+
+impl<H: Hasher> Hasher for CollisionTrackingHasher<H> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        self.inner.write(bytes);
+        self.digest.write(bytes);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.inner.write_u8(i);
+        self.digest.write_u8(i);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.inner.write_u16(i);
+        self.digest.write_u16(i);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.inner.write_u32(i);
+        self.digest.write_u32(i);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.inner.write_u64(i);
+        self.digest.write_u64(i);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.inner.write_u128(i);
+        self.digest.write_u128(i);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.inner.write_usize(i);
+        self.digest.write_usize(i);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        let hash = self.inner.finish();
+        let digest = self.digest.finish();
+
+        let mut tracker = self.tracker.lock().unwrap();
+        if tracker.keys.insert((hash, digest)) && !tracker.hashes.insert(hash) {
+            tracker.collisions += 1;
+        }
+
+        hash
+    }
+}
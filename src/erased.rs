@@ -0,0 +1,20 @@
+use core::hash::Hash;
+
+use crate::fast::FoldHasher;
+
+/// An object-safe version of [`Hash`] for [`fast::FoldHasher`](FoldHasher),
+/// allowing heterogeneous values to be hashed through `&dyn ErasedHash`.
+///
+/// It is implemented for every type implementing [`Hash`], and hashes exactly
+/// like it.
+pub trait ErasedHash {
+    /// Feeds this value into the given hasher, like [`Hash::hash`].
+    fn erased_hash(&self, hasher: &mut FoldHasher);
+}
+
+impl<T: Hash + ?Sized> ErasedHash for T {
+    #[inline]
+    fn erased_hash(&self, hasher: &mut FoldHasher) {
+        self.hash(hasher);
+    }
+}
@@ -0,0 +1,197 @@
+use core::hash::{BuildHasher, Hasher};
+use core::marker::PhantomData;
+
+use crate::fast::FoldHasher;
+use crate::{folded_multiply, ARBITRARY0};
+
+/// The final step turning the absorbed state of a [`fast::FoldHasher`](FoldHasher)
+/// into a hash value, for use with [`FinalizeState`].
+///
+/// `acc` is what [`Hasher::finish`] on the fast hasher returns, `len` is the
+/// total number of bytes written (counting integers by their width) and
+/// `seed` is a word of the global seed. Note that what exactly is absorbed
+/// into `acc` is not part of foldhash's stability guarantees, so the output of
+/// a custom finalizer can change between versions of foldhash, just like the
+/// built-in ones.
+pub trait Finalize {
+    /// Computes the hash value.
+    fn finalize(acc: u64, len: u64, seed: u64) -> u64;
+}
+
+/// The finalizer of [`fast::FoldHasher`](FoldHasher), which returns `acc` as-is.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FastFinalize;
+
+impl Finalize for FastFinalize {
+    #[inline(always)]
+    fn finalize(acc: u64, _len: u64, _seed: u64) -> u64 {
+        acc
+    }
+}
+
+/// The finalizer of [`quality::FoldHasher`](crate::quality::FoldHasher), one
+/// extra folded multiply.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct QualityFinalize;
+
+impl Finalize for QualityFinalize {
+    #[inline(always)]
+    fn finalize(acc: u64, _len: u64, _seed: u64) -> u64 {
+        folded_multiply(acc, ARBITRARY0)
+    }
+}
+
+/// A [`BuildHasher`] wrapper which replaces the final step of the
+/// [`fast::FoldHasher`](FoldHasher)s built by `S` with the finalizer `F`.
+///
+/// ```rust
+/// use std::hash::BuildHasher;
+/// use foldhash::fast::FixedState;
+/// use foldhash::{FastFinalize, Finalize, FinalizeState};
+///
+/// // MurmurHash3's fmix64.
+/// struct Fmix;
+///
+/// impl Finalize for Fmix {
+///     fn finalize(acc: u64, len: u64, _seed: u64) -> u64 {
+///         let mut h = acc ^ len;
+///         h ^= h >> 33;
+///         h = h.wrapping_mul(0xff51afd7ed558ccd);
+///         h ^= h >> 33;
+///         h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+///         h ^ (h >> 33)
+///     }
+/// }
+///
+/// let state = FixedState::with_seed(42);
+/// let fmix = FinalizeState::<_, Fmix>::new(state);
+/// let fast = FinalizeState::<_, FastFinalize>::new(state);
+/// assert_eq!(fast.hash_one("hello"), state.hash_one("hello"));
+/// assert_ne!(fmix.hash_one("hello"), state.hash_one("hello"));
+/// ```
+pub struct FinalizeState<S, F> {
+    inner: S,
+    finalize: PhantomData<fn() -> F>,
+}
+
+impl<S, F> FinalizeState<S, F> {
+    /// Creates a [`FinalizeState`] wrapping the given state.
+    #[inline(always)]
+    pub const fn new(inner: S) -> Self {
+        Self {
+            inner,
+            finalize: PhantomData,
+        }
+    }
+
+    /// Returns the inner state.
+    #[inline(always)]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    #[inline(always)]
+    pub(crate) fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: Clone, F> Clone for FinalizeState<S, F> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<S: Copy, F> Copy for FinalizeState<S, F> {}
+
+impl<S: core::fmt::Debug, F> core::fmt::Debug for FinalizeState<S, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FinalizeState").field(&self.inner).finish()
+    }
+}
+
+impl<S: Default, F> Default for FinalizeState<S, F> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: BuildHasher<Hasher = FoldHasher>, F: Finalize> BuildHasher for FinalizeState<S, F> {
+    type Hasher = FinalizeHasher<F>;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> FinalizeHasher<F> {
+        FinalizeHasher {
+            inner: self.inner.build_hasher(),
+            len: 0,
+            finalize: PhantomData,
+        }
+    }
+}
+
+/// A [`Hasher`] built by [`FinalizeState`], a [`fast::FoldHasher`](FoldHasher)
+/// with a custom finalizer.
+pub struct FinalizeHasher<F> {
+    inner: FoldHasher,
+    len: u64,
+    finalize: PhantomData<fn() -> F>,
+}
+
+impl<F> Clone for FinalizeHasher<F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner,
+            len: self.len,
+            finalize: PhantomData,
+        }
+    }
+}
+
+impl<F: Finalize> Hasher for FinalizeHasher<F> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        self.len += bytes.len() as u64;
+        self.inner.write(bytes);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.len += 1;
+        self.inner.write_u8(i);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.len += 2;
+        self.inner.write_u16(i);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.len += 4;
+        self.inner.write_u32(i);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.len += 8;
+        self.inner.write_u64(i);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.len += 16;
+        self.inner.write_u128(i);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.len += core::mem::size_of::<usize>() as u64;
+        self.inner.write_usize(i);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        F::finalize(self.inner.finish(), self.len, self.inner.fold_seed)
+    }
+}
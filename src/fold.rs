@@ -0,0 +1,191 @@
+use super::{folded_multiply, ARBITRARY10, ARBITRARY11, ARBITRARY12};
+
+/// Folds a sequence of precomputed 64-bit hashes into a single hash,
+/// **sensitive to their order**.
+///
+/// Useful for pipelines that already produce one hash per item and need a
+/// composite hash of the whole sequence. Use [`UnorderedHashFold`] instead if
+/// the order of the items should not matter.
+///
+/// ```rust
+/// use foldhash::HashFold;
+///
+/// let ab: HashFold = [1, 2].into_iter().collect();
+/// let ba: HashFold = [2, 1].into_iter().collect();
+/// assert_ne!(ab.finish(), ba.finish());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct HashFold {
+    state: u64,
+}
+
+impl HashFold {
+    /// Creates an empty [`HashFold`] with the given seed.
+    #[inline(always)]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: seed ^ ARBITRARY12,
+        }
+    }
+
+    /// Folds the next hash of the sequence into the state.
+    #[inline(always)]
+    pub fn push(&mut self, hash: u64) {
+        self.state = fold_step(self.state, hash);
+    }
+
+    /// Returns the composite hash of all hashes pushed so far.
+    #[inline(always)]
+    pub fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+impl Default for HashFold {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Extend<u64> for HashFold {
+    #[inline]
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, iter: I) {
+        for hash in iter {
+            self.push(hash);
+        }
+    }
+}
+
+impl FromIterator<u64> for HashFold {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut fold = Self::default();
+        fold.extend(iter);
+        fold
+    }
+}
+
+#[inline(always)]
+const fn fold_step(state: u64, hash: u64) -> u64 {
+    folded_multiply(state ^ hash, ARBITRARY11)
+}
+
+/// Combines two hashes in order, giving the same result as pushing them into
+/// a [`HashFold`] created with `seed`.
+///
+/// Being a `const fn` this can compute composite keys at compile time.
+///
+/// ```rust
+/// use foldhash::{combine2, HashFold};
+///
+/// const KEY: u64 = combine2(1, 2, 42);
+/// let mut fold = HashFold::new(42);
+/// fold.extend([1, 2]);
+/// assert_eq!(KEY, fold.finish());
+/// assert_ne!(KEY, combine2(2, 1, 42));
+/// ```
+#[inline(always)]
+pub const fn combine2(a: u64, b: u64, seed: u64) -> u64 {
+    let state = HashFold::new(seed).state;
+    fold_step(fold_step(state, a), b)
+}
+
+/// Combines three hashes in order, see [`combine2`].
+///
+/// ```rust
+/// use foldhash::{combine3, HashFold};
+///
+/// let mut fold = HashFold::new(42);
+/// fold.extend([1, 2, 3]);
+/// assert_eq!(combine3(1, 2, 3, 42), fold.finish());
+/// ```
+#[inline(always)]
+pub const fn combine3(a: u64, b: u64, c: u64, seed: u64) -> u64 {
+    fold_step(combine2(a, b, seed), c)
+}
+
+/// Combines four hashes in order, see [`combine2`].
+///
+/// ```rust
+/// use foldhash::{combine4, HashFold};
+///
+/// let mut fold = HashFold::new(42);
+/// fold.extend([1, 2, 3, 4]);
+/// assert_eq!(combine4(1, 2, 3, 4, 42), fold.finish());
+/// ```
+#[inline(always)]
+pub const fn combine4(a: u64, b: u64, c: u64, d: u64, seed: u64) -> u64 {
+    fold_step(combine3(a, b, c, seed), d)
+}
+
+/// Folds a collection of precomputed 64-bit hashes into a single hash,
+/// **independent of their order**.
+///
+/// The hashes are combined by wrapping addition along with their count, so
+/// equal hashes don't cancel out and the result acts as a multiset
+/// fingerprint. Use [`HashFold`] instead if the order of the items matters.
+///
+/// ```rust
+/// use foldhash::UnorderedHashFold;
+///
+/// let ab: UnorderedHashFold = [1, 2].into_iter().collect();
+/// let ba: UnorderedHashFold = [2, 1].into_iter().collect();
+/// let aa: UnorderedHashFold = [1, 1].into_iter().collect();
+/// assert_eq!(ab.finish(), ba.finish());
+/// assert_ne!(aa.finish(), [1].into_iter().collect::<UnorderedHashFold>().finish());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct UnorderedHashFold {
+    sum: u64,
+    count: u64,
+}
+
+impl UnorderedHashFold {
+    /// Creates an empty [`UnorderedHashFold`] with the given seed.
+    #[inline(always)]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            sum: seed,
+            count: 0,
+        }
+    }
+
+    /// Adds a hash to the collection.
+    #[inline(always)]
+    pub fn push(&mut self, hash: u64) {
+        self.sum = self.sum.wrapping_add(hash);
+        self.count += 1;
+    }
+
+    /// Returns the composite hash of all hashes pushed so far.
+    #[inline(always)]
+    pub fn finish(&self) -> u64 {
+        folded_multiply(self.sum ^ ARBITRARY10, self.count ^ ARBITRARY11)
+    }
+}
+
+impl Default for UnorderedHashFold {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Extend<u64> for UnorderedHashFold {
+    #[inline]
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, iter: I) {
+        for hash in iter {
+            self.push(hash);
+        }
+    }
+}
+
+impl FromIterator<u64> for UnorderedHashFold {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut fold = Self::default();
+        fold.extend(iter);
+        fold
+    }
+}
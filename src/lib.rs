@@ -10,7 +10,10 @@
 //!
 //! - You expect foldhash to have a consistent output across versions or
 //!   platforms, such as for persistent file formats or communication protocols.
-//!   
+//!   Hash values do change between versions. For example, an empty
+//!   [`Hasher::write`] no longer affects the hash, which changed the hashes of
+//!   all values that write an empty slice, including empty `str`s and `[u8]`s.
+//!
 //! - You are relying on foldhash's properties for any kind of security.
 //!   Foldhash is **not appropriate for any cryptographic purpose**.
 //!
@@ -21,7 +24,26 @@
 //! [MinHash](https://en.wikipedia.org/wiki/MinHash).
 //!
 //! Foldhash can be used in a `#![no_std]` environment by disabling its default
-//! `"std"` feature.
+//! `"std"` feature. The `"alloc"` feature, implied by `"std"`, enables the
+//! helpers which need the `alloc` crate, such as hashing `Cow`s.
+//!
+//! Enabling the `"harden"` feature makes the first use of a randomly seeded
+//! hasher panic if the global seed could not be mixed with the current time,
+//! rather than silently continuing with a seed derived only from memory
+//! addresses. Only the clock is checked, not whether those addresses are
+//! randomized. This requires `"std"`, and always panics on targets without a
+//! clock such as `wasm32-unknown-unknown`.
+//!
+//! The `"default-quality"` feature makes [`DefaultState`], and with it the
+//! [`HashMap`] and [`HashSet`] aliases, use [`quality::RandomState`] instead of
+//! [`fast::RandomState`].
+//!
+//! The `"diagnostics"` feature provides [`CollisionTrackingState`], a wrapper
+//! which counts distinct keys with colliding hashes to help troubleshoot slow
+//! hash maps. In debug builds it also makes [`fast::FoldHasher`] print a
+//! one-time warning to stderr after a long run of single-byte writes, which
+//! usually means a slice is hashed element by element instead of with a single
+//! [`Hasher::write`].
 //!
 //! # Usage
 //!
@@ -85,14 +107,63 @@
 #![cfg_attr(all(not(test), not(feature = "std")), no_std)]
 #![warn(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::hash::Hasher;
 
+mod address;
+mod builder;
 #[cfg(feature = "std")]
 mod convenience;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod erased;
+mod finalize;
+mod fold;
+mod output;
+mod primitive;
+pub mod rolling;
 mod seed;
+mod stream;
+pub mod testing;
+mod transform;
 
+pub use address::{Address, ByAddress};
+pub use builder::FoldBuildHasher;
 #[cfg(feature = "std")]
 pub use convenience::*;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::{CollisionTrackingHasher, CollisionTrackingState};
+pub use erased::ErasedHash;
+pub use finalize::{FastFinalize, Finalize, FinalizeHasher, FinalizeState, QualityFinalize};
+pub use fold::{combine2, combine3, combine4, HashFold, UnorderedHashFold};
+pub use output::*;
+pub use primitive::{NonZeroPrimitive, Primitive};
+pub use seed::fast::GlobalDefault;
+pub use seed::{global_seed_fingerprint, init, seed_from_label, SeedSequence};
+pub use stream::ByteSource;
+pub use transform::TransformHasher;
+
+/// The [`BuildHasher`](core::hash::BuildHasher) used by the [`HashMap`] and
+/// [`HashSet`] aliases.
+///
+/// This is [`fast::RandomState`], or [`quality::RandomState`] if the
+/// `"default-quality"` feature is enabled.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use foldhash::DefaultState;
+///
+/// let mut map: HashMap<u32, &str, DefaultState> = HashMap::default();
+/// map.insert(1, "one");
+/// ```
+pub type DefaultState = DefaultInner;
+
+#[cfg(not(feature = "default-quality"))]
+type DefaultInner = fast::RandomState;
+#[cfg(feature = "default-quality")]
+type DefaultInner = quality::RandomState;
 
 // Arbitrary constants with high entropy. Hexadecimal digits of pi were used.
 const ARBITRARY0: u64 = 0x243f6a8885a308d3;
@@ -105,6 +176,36 @@ const ARBITRARY6: u64 = 0xc0ac29b7c97c50dd;
 const ARBITRARY7: u64 = 0x3f84d5b5b5470917;
 const ARBITRARY8: u64 = 0x9216d5d98979fb1b;
 const ARBITRARY9: u64 = 0xd1310ba698dfb5ac;
+const ARBITRARY10: u64 = 0x2ffd72dbd01adfb7;
+const ARBITRARY11: u64 = 0xb8e1afed6a267e96;
+const ARBITRARY12: u64 = 0xba7c9045f12c7f99;
+const ARBITRARY13: u64 = 0x24a19947b3916cf7;
+const ARBITRARY14: u64 = 0x0801f2e2858efc16;
+const ARBITRARY15: u64 = 0x636920d871574e69;
+const ARBITRARY16: u64 = 0xa458fea3f4933d7e;
+const ARBITRARY17: u64 = 0x0d95748f728eb658;
+const ARBITRARY19: u64 = 0x7b54a41dc25a59b5;
+const ARBITRARY20: u64 = 0x9c30d5392af26013;
+const ARBITRARY21: u64 = 0xc5d1b023286085f0;
+const ARBITRARY22: u64 = 0xca417918b8db38ef;
+const ARBITRARY23: u64 = 0x8e79dcb0603a180e;
+const ARBITRARY24: u64 = 0x6c9e0e8bb01e8a3e;
+const ARBITRARY25: u64 = 0xd71577c1bd314b27;
+const ARBITRARY26: u64 = 0x78af2fda55605c60;
+const ARBITRARY27: u64 = 0xe65525f3aa55ab94;
+const ARBITRARY28: u64 = 0x5748986263e81440;
+const ARBITRARY29: u64 = 0x55ca396a2aab10b6;
+
+/// The largest input length, in bytes, that [`Hasher::write`] hashes with its
+/// small-input path: a single folded multiply of the first and last bytes.
+pub const SMALL_MAX: usize = 16;
+
+/// The largest input length, in bytes, that [`Hasher::write`] hashes with its
+/// medium-input path, which processes 32 bytes per iteration from both ends.
+///
+/// Longer inputs go through the bulk path, which processes 64 bytes per
+/// iteration across four independent lanes.
+pub const MEDIUM_MAX: usize = 255;
 
 #[inline(always)]
 const fn folded_multiply(x: u64, y: u64) -> u64 {
@@ -146,25 +247,57 @@ const fn folded_multiply(x: u64, y: u64) -> u64 {
 pub mod fast {
     use super::*;
 
-    pub use seed::fast::{FixedState, RandomState};
+    pub use seed::fast::{
+        hash_one_bytes, hash_one_with_seeds, CompactFoldHasher, CompactState, ConstState,
+        FixedState, RandomState, RunReproducibleState, SeedableRandomState,
+    };
 
     /// A [`Hasher`] instance implementing foldhash, optimized for speed.
     ///
-    /// It can't be created directly, see [`RandomState`] or [`FixedState`].
-    #[derive(Clone)]
+    /// It can't be created directly, see [`RandomState`], [`SeedableRandomState`]
+    /// or [`FixedState`].
+    ///
+    /// Writing an empty slice with [`Hasher::write`] leaves the state of the
+    /// hasher untouched:
+    ///
+    /// ```rust
+    /// use std::hash::{BuildHasher, Hasher};
+    /// use foldhash::fast::FixedState;
+    ///
+    /// let mut a = FixedState::with_seed(42).build_hasher();
+    /// let mut b = a.clone();
+    /// a.write(b"hello");
+    /// b.write(b"hello");
+    /// b.write(&[]);
+    /// assert_eq!(a.finish(), b.finish());
+    /// ```
+    #[derive(Copy, Clone)]
     pub struct FoldHasher {
-        accumulator: u64,
+        pub(crate) accumulator: u64,
         sponge: u128,
         sponge_len: u8,
-        fold_seed: u64,
-        expand_seed: u64,
-        expand_seed2: u64,
-        expand_seed3: u64,
+        pub(crate) fold_seed: u64,
+        pub(crate) expand_seed: u64,
+        pub(crate) expand_seed2: u64,
+        pub(crate) expand_seed3: u64,
+        #[cfg(all(feature = "diagnostics", debug_assertions))]
+        byte_writes: u32,
     }
 
+    /// The number of consecutive single-byte writes after which a
+    /// [`FoldHasher`] warns with the `"diagnostics"` feature in debug builds.
+    #[cfg(all(feature = "diagnostics", debug_assertions))]
+    const MAX_BYTE_WRITES: u32 = 256;
+
+    /// Whether the byte loop warning was printed, it's only printed once per
+    /// process.
+    #[cfg(all(feature = "diagnostics", debug_assertions))]
+    static BYTE_WRITES_WARNED: core::sync::atomic::AtomicBool =
+        core::sync::atomic::AtomicBool::new(false);
+
     impl FoldHasher {
         #[inline]
-        pub(crate) fn with_seed(per_hasher_seed: u64, global_seed: &[u64; 4]) -> FoldHasher {
+        pub(crate) const fn with_seed(per_hasher_seed: u64, global_seed: &[u64; 4]) -> FoldHasher {
             FoldHasher {
                 accumulator: per_hasher_seed,
                 sponge: 0,
@@ -173,12 +306,41 @@ pub mod fast {
                 expand_seed: global_seed[1],
                 expand_seed2: global_seed[2],
                 expand_seed3: global_seed[3],
+                #[cfg(all(feature = "diagnostics", debug_assertions))]
+                byte_writes: 0,
+            }
+        }
+
+        /// Counts consecutive single-byte writes, see [`MAX_BYTE_WRITES`].
+        #[inline(always)]
+        fn track_write(&mut self, _single_byte: bool) {
+            #[cfg(all(feature = "diagnostics", debug_assertions))]
+            {
+                use core::sync::atomic::Ordering;
+
+                self.byte_writes = if _single_byte {
+                    self.byte_writes.saturating_add(1)
+                } else {
+                    0
+                };
+                // Only a warning: std's Hash impls for e.g. Vec<bool> or
+                // Vec<(u8, u8)> write single bytes per element, and users can't
+                // change those.
+                if self.byte_writes == MAX_BYTE_WRITES + 1
+                    && !BYTE_WRITES_WARNED.swap(true, Ordering::Relaxed)
+                {
+                    eprintln!(
+                        "foldhash: more than {MAX_BYTE_WRITES} consecutive single-byte writes, \
+                         hash byte slices with a single `Hasher::write` instead"
+                    );
+                }
             }
         }
 
         #[inline(always)]
         fn write_num<T: Into<u128>>(&mut self, x: T) {
             let bits: usize = 8 * core::mem::size_of::<T>();
+            self.track_write(bits == 8);
             if self.sponge_len as usize + bits > 128 {
                 let lo = self.sponge as u64;
                 let hi = (self.sponge >> 64) as u64;
@@ -190,15 +352,200 @@ pub mod fast {
                 self.sponge_len += bits as u8;
             }
         }
+
+        /// Splits the finished hash into a Cuckoo filter fingerprint and the
+        /// two candidate buckets for it, in that order.
+        ///
+        /// The fingerprint is never zero, the second bucket is derived from
+        /// the first using [`cuckoo_alt_bucket`](crate::cuckoo_alt_bucket).
+        /// `num_buckets` must be a power of two.
+        #[inline(always)]
+        pub fn cuckoo_parts(&self, num_buckets: usize) -> (u8, usize, usize) {
+            output::cuckoo_parts(self.finish(), num_buckets)
+        }
+
+        /// Returns the hash value with `extra` folded into it, for salting at
+        /// finish time (e.g. with a table generation counter).
+        ///
+        /// This is exactly [`finish`](Hasher::finish) when `extra` is zero.
+        /// For any other value the finished hash goes through one more folded
+        /// multiply, which redistributes all bits and thus breaks up bucket
+        /// collisions between keys. Keys whose full 64-bit [`finish`](Hasher::finish)
+        /// outputs collide still collide for every `extra`.
+        #[inline(always)]
+        pub fn finish_mixed(&self, extra: u64) -> u64 {
+            let hash = self.finish();
+            if extra == 0 {
+                hash
+            } else {
+                folded_multiply(hash ^ extra, self.fold_seed)
+            }
+        }
+
+        /// The algorithm version stored by [`finish_tagged`](Self::finish_tagged).
+        ///
+        /// This is bumped whenever the hash values of this hasher change.
+        pub const TAG_VERSION: u8 = 1;
+
+        /// Returns the hash value with its top byte replaced by
+        /// [`TAG_VERSION`](Self::TAG_VERSION), for self-describing stored
+        /// hashes.
+        ///
+        /// Only the low 56 bits of the hash are kept. A reader can compare the
+        /// top byte against the current [`TAG_VERSION`](Self::TAG_VERSION) to
+        /// detect and recompute hashes produced by an older algorithm.
+        ///
+        /// ```rust
+        /// use std::hash::{BuildHasher, Hasher};
+        /// use foldhash::fast::{FixedState, FoldHasher};
+        ///
+        /// let mut hasher = FixedState::with_seed(42).build_hasher();
+        /// hasher.write(b"cache entry");
+        /// let tagged = hasher.finish_tagged();
+        /// assert_eq!((tagged >> 56) as u8, FoldHasher::TAG_VERSION);
+        /// assert_eq!(tagged << 8, hasher.finish() << 8);
+        /// ```
+        #[inline(always)]
+        pub fn finish_tagged(&self) -> u64 {
+            ((Self::TAG_VERSION as u64) << 56) | (self.finish() & ((1 << 56) - 1))
+        }
+
+        /// Returns the hash value together with its SwissTable control byte.
+        ///
+        /// The control byte matches hashbrown's `h2`: the top 7 bits of the
+        /// hash after truncating it to a `usize`, so bits 57..64 on 64-bit
+        /// targets and bits 25..32 on 32-bit targets. The high bit of the
+        /// control byte is always zero.
+        ///
+        /// ```rust
+        /// use std::hash::{BuildHasher, Hasher};
+        /// use foldhash::fast::FixedState;
+        ///
+        /// let mut hasher = FixedState::with_seed(42).build_hasher();
+        /// hasher.write_u64(1337);
+        /// let (hash, control) = hasher.finish_with_control();
+        /// assert_eq!(hash, hasher.finish());
+        /// assert!(control < 0x80);
+        /// ```
+        #[inline(always)]
+        pub fn finish_with_control(&self) -> (u64, u8) {
+            let hash = self.finish();
+            (hash, output::control_byte(hash))
+        }
+
+        /// Mixes a precomputed digest from another hash function (e.g. a CRC)
+        /// into the state.
+        ///
+        /// This is the supported way to combine a foreign digest with data
+        /// hashed by foldhash. The digest is folded in with a dedicated
+        /// constant, so it isn't interchangeable with [`write_u64`](Hasher::write_u64)
+        /// of the same value. It is ordered with respect to all other writes.
+        ///
+        /// ```rust
+        /// use std::hash::{BuildHasher, Hasher};
+        /// use foldhash::fast::FixedState;
+        ///
+        /// let state = FixedState::with_seed(42);
+        /// let crc = 0x1234_5678;
+        ///
+        /// let mut a = state.build_hasher();
+        /// a.absorb_digest(crc);
+        /// a.write(b"body");
+        ///
+        /// let mut b = state.build_hasher();
+        /// b.write(b"body");
+        /// b.absorb_digest(crc);
+        ///
+        /// let mut c = state.build_hasher();
+        /// c.write_u64(crc);
+        /// c.write(b"body");
+        ///
+        /// assert_ne!(a.finish(), b.finish());
+        /// assert_ne!(a.finish(), c.finish());
+        /// ```
+        #[inline(always)]
+        pub fn absorb_digest(&mut self, digest: u64) {
+            self.track_write(false);
+            // Fold buffered integers first so they stay ordered before the
+            // digest.
+            if self.sponge_len > 0 {
+                let lo = self.sponge as u64;
+                let hi = (self.sponge >> 64) as u64;
+                self.accumulator = folded_multiply(lo ^ self.accumulator, hi ^ self.fold_seed);
+                self.sponge = 0;
+                self.sponge_len = 0;
+            }
+            self.accumulator =
+                folded_multiply(digest ^ self.accumulator, ARBITRARY26 ^ self.fold_seed);
+        }
+
+        /// Upgrades this hasher to a [`quality::FoldHasher`] without restarting
+        /// the hash.
+        ///
+        /// The quality hasher only differs in its final avalanche step, so the
+        /// bytes already written get exactly the same treatment as if they had
+        /// been written to a quality hasher with the same seeds from the start.
+        ///
+        /// ```rust
+        /// use std::hash::{BuildHasher, Hasher};
+        /// use foldhash::{fast, quality};
+        ///
+        /// let mut hasher = fast::FixedState::with_seed(42).build_hasher();
+        /// hasher.write(b"hello");
+        ///
+        /// let mut upgraded = hasher.clone().into_quality();
+        /// let mut converted = quality::FoldHasher::from(hasher);
+        /// upgraded.write_u32(7);
+        /// converted.write_u32(7);
+        /// assert_eq!(upgraded.finish(), converted.finish());
+        /// ```
+        #[inline(always)]
+        pub fn into_quality(self) -> quality::FoldHasher {
+            quality::FoldHasher { inner: self }
+        }
+
+        /// Writes the bytes produced by an iterator, with the same effect as
+        /// a single [`write`](Hasher::write) of the collected bytes.
+        ///
+        /// The bytes are gathered in blocks on the stack and absorbed a block
+        /// at a time, no heap buffer is needed.
+        ///
+        /// ```rust
+        /// use std::hash::{BuildHasher, Hasher};
+        /// use foldhash::fast::FixedState;
+        ///
+        /// let state = FixedState::with_seed(42);
+        /// let mut a = state.build_hasher();
+        /// let mut b = state.build_hasher();
+        /// a.write_iter((0..1000u32).map(|i| i as u8));
+        /// b.write(&(0..1000u32).map(|i| i as u8).collect::<Vec<_>>());
+        /// assert_eq!(a.finish(), b.finish());
+        /// ```
+        pub fn write_iter<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+            let mut stream = stream::ByteStream::new(self);
+            let mut block = [0u8; 64];
+            let mut len = 0;
+            for byte in iter {
+                block[len] = byte;
+                len += 1;
+                if len == block.len() {
+                    stream.write(&block);
+                    len = 0;
+                }
+            }
+            stream.write(&block[..len]);
+            stream.finish();
+        }
     }
 
     impl Hasher for FoldHasher {
         #[inline(always)]
         fn write(&mut self, bytes: &[u8]) {
+            self.track_write(false);
             let mut s0 = self.accumulator;
             let mut s1 = self.expand_seed;
             let len = bytes.len();
-            if len <= 16 {
+            if len <= SMALL_MAX {
                 // XOR the input into s0, s1, then multiply and fold.
                 if len >= 8 {
                     s0 ^= u64::from_ne_bytes(bytes[0..8].try_into().unwrap());
@@ -212,9 +559,14 @@ pub mod fast {
                     let hi = bytes[len - 1];
                     s0 ^= lo as u64;
                     s1 ^= ((hi as u64) << 8) | mid as u64;
+                } else {
+                    // Writing nothing leaves the state untouched.
+                    return;
                 }
                 self.accumulator = folded_multiply(s0, s1);
-            } else if len < 256 {
+            } else if len <= 2 * SMALL_MAX {
+                self.accumulator = hash_bytes_17_32(bytes, s0, s1, self.fold_seed);
+            } else if len <= MEDIUM_MAX {
                 self.accumulator = hash_bytes_medium(bytes, s0, s1, self.fold_seed);
             } else {
                 self.accumulator = hash_bytes_long(
@@ -250,6 +602,7 @@ pub mod fast {
 
         #[inline(always)]
         fn write_u128(&mut self, i: u128) {
+            self.track_write(false);
             let lo = i as u64;
             let hi = (i >> 64) as u64;
             self.accumulator = folded_multiply(lo ^ self.accumulator, hi ^ self.fold_seed);
@@ -286,11 +639,68 @@ pub mod quality {
     /// A [`Hasher`] instance implementing foldhash, optimized for quality.
     ///
     /// It can't be created directly, see [`RandomState`] or [`FixedState`].
-    #[derive(Clone)]
+    #[derive(Copy, Clone)]
     pub struct FoldHasher {
         pub(crate) inner: fast::FoldHasher,
     }
 
+    impl FoldHasher {
+        /// Splits the finished hash into a Cuckoo filter fingerprint and the
+        /// two candidate buckets for it, in that order.
+        ///
+        /// The fingerprint is never zero, the second bucket is derived from
+        /// the first using [`cuckoo_alt_bucket`](crate::cuckoo_alt_bucket).
+        /// `num_buckets` must be a power of two.
+        #[inline(always)]
+        pub fn cuckoo_parts(&self, num_buckets: usize) -> (u8, usize, usize) {
+            output::cuckoo_parts(self.finish(), num_buckets)
+        }
+
+        /// Returns the hash value with `extra` folded into it, for salting at
+        /// finish time (e.g. with a table generation counter).
+        ///
+        /// This is exactly [`finish`](Hasher::finish) when `extra` is zero,
+        /// see [`fast::FoldHasher::finish_mixed`] for the distribution impact
+        /// of other values.
+        ///
+        /// ```rust
+        /// use std::hash::{BuildHasher, Hasher};
+        /// use foldhash::quality::RandomState;
+        ///
+        /// let mut hasher = RandomState::default().build_hasher();
+        /// hasher.write(b"key");
+        /// assert_eq!(hasher.finish_mixed(0), hasher.finish());
+        /// assert_ne!(hasher.finish_mixed(1), hasher.finish_mixed(2));
+        /// ```
+        #[inline(always)]
+        pub fn finish_mixed(&self, extra: u64) -> u64 {
+            folded_multiply(self.inner.finish_mixed(extra), ARBITRARY0)
+        }
+
+        /// Mixes a precomputed digest from another hash function into the
+        /// state, see [`fast::FoldHasher::absorb_digest`].
+        #[inline(always)]
+        pub fn absorb_digest(&mut self, digest: u64) {
+            self.inner.absorb_digest(digest);
+        }
+
+        /// Returns the hash value together with its SwissTable control byte.
+        ///
+        /// See [`fast::FoldHasher::finish_with_control`] for the bit layout.
+        #[inline(always)]
+        pub fn finish_with_control(&self) -> (u64, u8) {
+            let hash = self.finish();
+            (hash, output::control_byte(hash))
+        }
+    }
+
+    impl From<fast::FoldHasher> for FoldHasher {
+        #[inline(always)]
+        fn from(inner: fast::FoldHasher) -> Self {
+            inner.into_quality()
+        }
+    }
+
     impl Hasher for FoldHasher {
         #[inline(always)]
         fn write(&mut self, bytes: &[u8]) {
@@ -335,7 +745,7 @@ pub mod quality {
 }
 
 /// Hashes strings >= 16 bytes, has unspecified behavior when bytes.len() < 16.
-fn hash_bytes_medium(bytes: &[u8], mut s0: u64, mut s1: u64, fold_seed: u64) -> u64 {
+pub(crate) fn hash_bytes_medium(bytes: &[u8], mut s0: u64, mut s1: u64, fold_seed: u64) -> u64 {
     // Process 32 bytes per iteration, 16 bytes from the start, 16 bytes from
     // the end. On the last iteration these two chunks can overlap, but that is
     // perfectly fine.
@@ -360,6 +770,37 @@ fn hash_bytes_medium(bytes: &[u8], mut s0: u64, mut s1: u64, fold_seed: u64) ->
     s0 ^ s1
 }
 
+/// Hashes 17 to 32 bytes with two overlapping 16-byte reads, exactly like the
+/// single iteration [`hash_bytes_medium`] does for these lengths, without the
+/// loop setup.
+#[inline(always)]
+fn hash_bytes_17_32(bytes: &[u8], s0: u64, s1: u64, fold_seed: u64) -> u64 {
+    let len = bytes.len();
+    let a = u64::from_ne_bytes(bytes[0..8].try_into().unwrap());
+    let b = u64::from_ne_bytes(bytes[8..16].try_into().unwrap());
+    let c = u64::from_ne_bytes(bytes[len - 16..len - 8].try_into().unwrap());
+    let d = u64::from_ne_bytes(bytes[len - 8..].try_into().unwrap());
+    folded_multiply(a ^ s0, c ^ fold_seed) ^ folded_multiply(b ^ s1, d ^ fold_seed)
+}
+
+/// Absorbs one 64-byte chunk of a long string into the four lanes.
+#[inline(always)]
+pub(crate) fn hash_chunk_long(chunk: &[u8], s: [&mut u64; 4], fold_seed: u64) {
+    let a = u64::from_ne_bytes(chunk[0..8].try_into().unwrap());
+    let b = u64::from_ne_bytes(chunk[8..16].try_into().unwrap());
+    let c = u64::from_ne_bytes(chunk[16..24].try_into().unwrap());
+    let d = u64::from_ne_bytes(chunk[24..32].try_into().unwrap());
+    let e = u64::from_ne_bytes(chunk[32..40].try_into().unwrap());
+    let f = u64::from_ne_bytes(chunk[40..48].try_into().unwrap());
+    let g = u64::from_ne_bytes(chunk[48..56].try_into().unwrap());
+    let h = u64::from_ne_bytes(chunk[56..64].try_into().unwrap());
+    let [s0, s1, s2, s3] = s;
+    *s0 = folded_multiply(a ^ *s0, e ^ fold_seed);
+    *s1 = folded_multiply(b ^ *s1, f ^ fold_seed);
+    *s2 = folded_multiply(c ^ *s2, g ^ fold_seed);
+    *s3 = folded_multiply(d ^ *s3, h ^ fold_seed);
+}
+
 /// Hashes strings >= 16 bytes, has unspecified behavior when bytes.len() < 16.
 #[cold]
 #[inline(never)]
@@ -374,18 +815,7 @@ fn hash_bytes_long(
     let chunks = bytes.chunks_exact(64);
     let remainder = chunks.remainder().len();
     for chunk in chunks {
-        let a = u64::from_ne_bytes(chunk[0..8].try_into().unwrap());
-        let b = u64::from_ne_bytes(chunk[8..16].try_into().unwrap());
-        let c = u64::from_ne_bytes(chunk[16..24].try_into().unwrap());
-        let d = u64::from_ne_bytes(chunk[24..32].try_into().unwrap());
-        let e = u64::from_ne_bytes(chunk[32..40].try_into().unwrap());
-        let f = u64::from_ne_bytes(chunk[40..48].try_into().unwrap());
-        let g = u64::from_ne_bytes(chunk[48..56].try_into().unwrap());
-        let h = u64::from_ne_bytes(chunk[56..64].try_into().unwrap());
-        s0 = folded_multiply(a ^ s0, e ^ fold_seed);
-        s1 = folded_multiply(b ^ s1, f ^ fold_seed);
-        s2 = folded_multiply(c ^ s2, g ^ fold_seed);
-        s3 = folded_multiply(d ^ s3, h ^ fold_seed);
+        hash_chunk_long(chunk, [&mut s0, &mut s1, &mut s2, &mut s3], fold_seed);
     }
     s0 ^= s2;
     s1 ^= s3;
@@ -0,0 +1,154 @@
+use super::{folded_multiply, ARBITRARY9};
+
+/// Computes the alternate Cuckoo filter bucket for an item with the given
+/// fingerprint currently stored in `bucket`.
+///
+/// This is the standard partial-key Cuckoo hashing relation: the alternate
+/// bucket is `bucket` XOR a mix of the fingerprint, so applying it twice
+/// returns the original bucket. `num_buckets` must be a power of two.
+///
+/// ```rust
+/// use std::hash::{BuildHasher, Hasher};
+/// use foldhash::{cuckoo_alt_bucket, fast::FixedState};
+///
+/// let mut hasher = FixedState::with_seed(42).build_hasher();
+/// hasher.write(b"hello world");
+/// let (fingerprint, b1, b2) = hasher.cuckoo_parts(1024);
+/// assert_eq!(cuckoo_alt_bucket(b1, fingerprint, 1024), b2);
+/// assert_eq!(cuckoo_alt_bucket(b2, fingerprint, 1024), b1);
+/// ```
+#[inline(always)]
+pub fn cuckoo_alt_bucket(bucket: usize, fingerprint: u8, num_buckets: usize) -> usize {
+    assert!(num_buckets.is_power_of_two());
+    (bucket ^ folded_multiply(fingerprint as u64, ARBITRARY9) as usize) & (num_buckets - 1)
+}
+
+#[inline(always)]
+pub(crate) fn cuckoo_parts(hash: u64, num_buckets: usize) -> (u8, usize, usize) {
+    assert!(num_buckets.is_power_of_two());
+
+    // The fingerprint comes from the top bits and the bucket from the low bits
+    // so they are independent. Zero is reserved to mark empty slots.
+    let fingerprint = ((hash >> 56) as u8).max(1);
+    let bucket = hash as usize & (num_buckets - 1);
+    let alt_bucket = cuckoo_alt_bucket(bucket, fingerprint, num_buckets);
+    (fingerprint, bucket, alt_bucket)
+}
+
+/// The 7-bit SwissTable control byte for a hash, as computed by hashbrown.
+#[inline(always)]
+pub(crate) fn control_byte(hash: u64) -> u8 {
+    // The top 7 bits of the hash as truncated to a usize.
+    let hash_bits = 8 * core::mem::size_of::<usize>().min(core::mem::size_of::<u64>());
+    ((hash >> (hash_bits - 7)) & 0x7f) as u8
+}
+
+/// Encodes a 64-bit hash as little-endian bytes.
+///
+/// This is the canonical byte layout for storing foldhash outputs, independent
+/// of the platform's endianness. Note that foldhash's hash values themselves
+/// are not stable across versions or platforms.
+///
+/// ```rust
+/// use foldhash::{hash_from_le_bytes, hash_to_le_bytes};
+///
+/// let bytes = hash_to_le_bytes(0x0123456789abcdef);
+/// assert_eq!(bytes[0], 0xef);
+/// assert_eq!(hash_from_le_bytes(bytes), 0x0123456789abcdef);
+/// ```
+#[inline(always)]
+pub const fn hash_to_le_bytes(hash: u64) -> [u8; 8] {
+    hash.to_le_bytes()
+}
+
+/// Decodes a 64-bit hash stored by [`hash_to_le_bytes`].
+#[inline(always)]
+pub const fn hash_from_le_bytes(bytes: [u8; 8]) -> u64 {
+    u64::from_le_bytes(bytes)
+}
+
+/// Encodes a 128-bit hash as little-endian bytes, see [`hash_to_le_bytes`].
+#[inline(always)]
+pub const fn hash128_to_le_bytes(hash: u128) -> [u8; 16] {
+    hash.to_le_bytes()
+}
+
+/// Decodes a 128-bit hash stored by [`hash128_to_le_bytes`].
+#[inline(always)]
+pub const fn hash128_from_le_bytes(bytes: [u8; 16]) -> u128 {
+    u128::from_le_bytes(bytes)
+}
+
+/// Renders a 64-bit hash as 16 lowercase ASCII hex digits, without allocating.
+///
+/// The digits are exactly those of `format!("{hash:016x}")`, most significant
+/// first, independent of the platform's endianness like [`hash_to_le_bytes`].
+///
+/// ```rust
+/// use foldhash::hash_hex;
+///
+/// let hash = 0x0123456789abcdef;
+/// assert_eq!(&hash_hex(hash), b"0123456789abcdef");
+/// assert_eq!(hash_hex(42), *format!("{:016x}", 42).as_bytes());
+/// ```
+#[inline]
+pub const fn hash_hex(hash: u64) -> [u8; 16] {
+    let mut out = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        out[i] = hex_digit(hash >> (60 - 4 * i));
+        i += 1;
+    }
+    out
+}
+
+/// Renders a 128-bit hash as 32 lowercase ASCII hex digits, see [`hash_hex`].
+///
+/// ```rust
+/// use foldhash::hash_hex_128;
+///
+/// let hash = 0x0123456789abcdef_fedcba9876543210;
+/// assert_eq!(&hash_hex_128(hash), b"0123456789abcdeffedcba9876543210");
+/// ```
+#[inline]
+pub const fn hash_hex_128(hash: u128) -> [u8; 32] {
+    let hi = hash_hex((hash >> 64) as u64);
+    let lo = hash_hex(hash as u64);
+    let mut out = [0; 32];
+    let mut i = 0;
+    while i < 16 {
+        out[i] = hi[i];
+        out[16 + i] = lo[i];
+        i += 1;
+    }
+    out
+}
+
+#[inline(always)]
+const fn hex_digit(nibble: u64) -> u8 {
+    b"0123456789abcdef"[(nibble & 0xf) as usize]
+}
+
+/// Maps a hash to a shard in `0..shards`.
+///
+/// This uses a multiply-high ("fastrange") reduction instead of a modulo, so it
+/// depends on all bits of the hash, weighted towards the high ones, and every
+/// shard receives either `floor` or `ceil` of `2^64 / shards` hash values. Use
+/// this everywhere a routing decision must agree between services.
+///
+/// # Panics
+///
+/// Panics if `shards` is zero.
+///
+/// ```rust
+/// use foldhash::shard;
+///
+/// assert_eq!(shard(0, 10), 0);
+/// assert_eq!(shard(u64::MAX, 10), 9);
+/// assert_eq!(shard(1 << 63, 10), 5);
+/// ```
+#[inline(always)]
+pub const fn shard(hash: u64, shards: u32) -> u32 {
+    assert!(shards > 0);
+    ((hash as u128 * shards as u128) >> 64) as u32
+}
@@ -0,0 +1,132 @@
+use core::hash::Hasher;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::fast::FoldHasher;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A scalar type which can be folded into a [`fast::FoldHasher`](FoldHasher)
+/// with a single fixed-width write.
+///
+/// Implemented for all integer types and their `NonZero` counterparts,
+/// [`bool`], [`char`], [`f32`] and [`f64`].
+/// Folding a value writes exactly what its [`Hash`](core::hash::Hash)
+/// implementation would, so no length prefix or other framing is involved.
+/// Floats don't implement `Hash` and are folded as their
+/// [`to_bits`](f64::to_bits) representation, which distinguishes `0.0` from
+/// `-0.0` and different NaN payloads.
+///
+/// This trait is sealed and can't be implemented outside of foldhash.
+pub trait Primitive: Copy + sealed::Sealed {
+    /// Writes this value into `hasher` using its fixed-width `write_*` method.
+    fn fold_into(self, hasher: &mut FoldHasher);
+}
+
+macro_rules! impl_primitive {
+    ($($t:ty => $write:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Primitive for $t {
+                #[inline(always)]
+                fn fold_into(self, hasher: &mut FoldHasher) {
+                    hasher.$write(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive! {
+    u8 => write_u8,
+    u16 => write_u16,
+    u32 => write_u32,
+    u64 => write_u64,
+    u128 => write_u128,
+    usize => write_usize,
+    i8 => write_i8,
+    i16 => write_i16,
+    i32 => write_i32,
+    i64 => write_i64,
+    i128 => write_i128,
+    isize => write_isize,
+}
+
+impl sealed::Sealed for bool {}
+
+impl Primitive for bool {
+    #[inline(always)]
+    fn fold_into(self, hasher: &mut FoldHasher) {
+        hasher.write_u8(self as u8);
+    }
+}
+
+impl sealed::Sealed for char {}
+
+impl Primitive for char {
+    #[inline(always)]
+    fn fold_into(self, hasher: &mut FoldHasher) {
+        hasher.write_u32(self as u32);
+    }
+}
+
+impl sealed::Sealed for f32 {}
+
+impl Primitive for f32 {
+    #[inline(always)]
+    fn fold_into(self, hasher: &mut FoldHasher) {
+        hasher.write_u32(self.to_bits());
+    }
+}
+
+impl sealed::Sealed for f64 {}
+
+impl Primitive for f64 {
+    #[inline(always)]
+    fn fold_into(self, hasher: &mut FoldHasher) {
+        hasher.write_u64(self.to_bits());
+    }
+}
+
+/// A `NonZero` integer type, which is folded exactly like its underlying
+/// integer, see [`fast::RandomState::hash_nonzero_in`](crate::fast::RandomState::hash_nonzero_in).
+///
+/// This trait is sealed and can't be implemented outside of foldhash.
+pub trait NonZeroPrimitive: Primitive {}
+
+macro_rules! impl_nonzero_primitive {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Primitive for $t {
+                #[inline(always)]
+                fn fold_into(self, hasher: &mut FoldHasher) {
+                    self.get().fold_into(hasher);
+                }
+            }
+
+            impl NonZeroPrimitive for $t {}
+        )*
+    };
+}
+
+impl_nonzero_primitive! {
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+}
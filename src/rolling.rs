@@ -0,0 +1,132 @@
+//! A rolling hash over a fixed-width window of bytes, for substring search.
+
+use crate::{folded_multiply, ARBITRARY27, ARBITRARY28, ARBITRARY29};
+
+/// A Rabin-Karp style rolling hash over the last `window` bytes.
+///
+/// The window is hashed as a polynomial `sum(b_i * base^(window - 1 - i))`
+/// modulo 2^64 with a seeded odd base, which lets [`roll`](Self::roll) slide
+/// the window by one byte in O(1). The polynomial is linear, so its value is
+/// passed through a folded multiply before it is returned. The hash of a
+/// window only depends on its bytes and the seed, not on how it was reached.
+///
+/// This is not HashDoS resistant: given the hashes of a few windows, the base
+/// can be recovered.
+///
+/// ```rust
+/// use foldhash::rolling::RollingHash;
+///
+/// let haystack = b"the quick brown fox";
+/// let needle = b"brown";
+/// let n = needle.len();
+///
+/// let mut fresh = RollingHash::new(n, 42);
+/// let target = needle.iter().map(|&b| fresh.push(b)).last().unwrap();
+///
+/// let mut rolling = RollingHash::new(n, 42);
+/// let mut hash = haystack[..n].iter().map(|&b| rolling.push(b)).last().unwrap();
+/// let mut matches = Vec::new();
+/// for i in n..=haystack.len() {
+///     if hash == target && &haystack[i - n..i] == needle {
+///         matches.push(i - n);
+///     }
+///     if i < haystack.len() {
+///         hash = rolling.roll(haystack[i - n], haystack[i]);
+///     }
+/// }
+/// assert_eq!(matches, [10]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RollingHash {
+    state: u64,
+    len: usize,
+    window: usize,
+    base: u64,
+    // base^window, the weight of the byte leaving the window after a roll.
+    base_pow: u64,
+    offset: u64,
+}
+
+impl RollingHash {
+    /// Creates an empty [`RollingHash`] for windows of `window` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(window: usize, seed: u64) -> Self {
+        assert!(window > 0, "window must not be empty");
+
+        // The base must be odd so that it is invertible modulo 2^64, otherwise
+        // bytes would shift out of the top of the polynomial.
+        let base = folded_multiply(seed ^ ARBITRARY27, ARBITRARY28) | 1;
+        let mut base_pow = 1u64;
+        let mut factor = base;
+        let mut exp = window;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                base_pow = base_pow.wrapping_mul(factor);
+            }
+            factor = factor.wrapping_mul(factor);
+            exp >>= 1;
+        }
+
+        Self {
+            state: 0,
+            len: 0,
+            window,
+            base,
+            base_pow,
+            // Offset the bytes so that leading zero bytes aren't ignored.
+            offset: seed ^ ARBITRARY28,
+        }
+    }
+
+    /// Appends a byte while filling the window, returning the hash of the
+    /// bytes pushed so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is already full, use [`roll`](Self::roll) then.
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> u64 {
+        assert!(self.len < self.window, "window is full, use roll");
+        self.len += 1;
+        self.state = self
+            .state
+            .wrapping_mul(self.base)
+            .wrapping_add(self.symbol(byte));
+        self.hash()
+    }
+
+    /// Slides the full window by one byte, dropping `out_byte` (the oldest
+    /// byte in the window) and appending `in_byte`, and returns the hash of
+    /// the new window.
+    ///
+    /// Passing an `out_byte` that isn't the oldest byte gives a meaningless
+    /// hash.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window isn't full yet, use [`push`](Self::push) then.
+    #[inline]
+    pub fn roll(&mut self, out_byte: u8, in_byte: u8) -> u64 {
+        assert!(self.len == self.window, "window isn't full, use push");
+        self.state = self
+            .state
+            .wrapping_mul(self.base)
+            .wrapping_add(self.symbol(in_byte))
+            .wrapping_sub(self.symbol(out_byte).wrapping_mul(self.base_pow));
+        self.hash()
+    }
+
+    /// Returns the hash of the current window.
+    #[inline(always)]
+    pub fn hash(&self) -> u64 {
+        folded_multiply(self.state, ARBITRARY29)
+    }
+
+    #[inline(always)]
+    fn symbol(&self, byte: u8) -> u64 {
+        (byte as u64).wrapping_add(self.offset)
+    }
+}
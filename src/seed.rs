@@ -1,85 +1,1359 @@
-use core::hash::BuildHasher;
+use core::hash::{BuildHasher, Hash, Hasher};
 
 // These constants may end up unused depending on platform support.
 #[allow(unused)]
 use crate::{ARBITRARY1, ARBITRARY9};
 
 use super::{
-    folded_multiply, ARBITRARY2, ARBITRARY3, ARBITRARY4, ARBITRARY5, ARBITRARY6, ARBITRARY7,
-    ARBITRARY8,
+    folded_multiply, UnorderedHashFold, ARBITRARY13, ARBITRARY14, ARBITRARY15, ARBITRARY16,
+    ARBITRARY17, ARBITRARY19, ARBITRARY2, ARBITRARY21, ARBITRARY22, ARBITRARY23, ARBITRARY24,
+    ARBITRARY25, ARBITRARY3, ARBITRARY4, ARBITRARY5, ARBITRARY6, ARBITRARY7, ARBITRARY8,
 };
 
 /// Used for FixedState, and RandomState if atomics for dynamic init are unavailable.
 const FIXED_GLOBAL_SEED: [u64; 4] = [ARBITRARY4, ARBITRARY5, ARBITRARY6, ARBITRARY7];
 
+/// Derives a seed from a label at compile time, for use with
+/// [`fast::ConstState`] or the `with_seed` constructors.
+///
+/// Different labels give unrelated seeds, so each logical table can have its
+/// own hash family without picking seeds by hand.
+///
+/// ```rust
+/// use foldhash::seed_from_label;
+///
+/// const USERS: u64 = seed_from_label("users");
+/// assert_ne!(USERS, seed_from_label("groups"));
+/// ```
+pub const fn seed_from_label(label: &str) -> u64 {
+    let bytes = label.as_bytes();
+    let mut seed = ARBITRARY13;
+    let mut i = 0;
+    while i < bytes.len() {
+        seed = folded_multiply(seed ^ bytes[i] as u64, ARBITRARY14);
+        i += 1;
+    }
+    folded_multiply(seed ^ bytes.len() as u64, ARBITRARY15)
+}
+
+/// Initializes the process-wide global seed used by the randomly seeded
+/// states, if it wasn't initialized yet.
+///
+/// The global seed is otherwise generated when the first random state is
+/// created, which is a one-time latency spike. Calling this during startup
+/// moves that work out of the hot path. It is cheap to call again, and safe
+/// to call from multiple threads at once.
+///
+/// ```rust
+/// foldhash::init();
+/// ```
+#[inline(always)]
+pub fn init() {
+    global::GlobalSeed::new();
+}
+
+/// Returns a fingerprint of the process-wide global seed used by the
+/// [`RandomState`](fast::RandomState)s, initializing it if it wasn't yet.
+///
+/// The four seed words are compressed into one with folded multiplies, which
+/// discards information, so the fingerprint can be logged without revealing
+/// the seed itself. Equal fingerprints on different processes indicate they
+/// share the same global seed, e.g. after importing it with
+/// [`RunReproducibleState::import_global_seed`](fast::RunReproducibleState::import_global_seed).
+///
+/// ```rust
+/// assert_eq!(foldhash::global_seed_fingerprint(), foldhash::global_seed_fingerprint());
+/// ```
+pub fn global_seed_fingerprint() -> u64 {
+    let [a, b, c, d] = *global::GlobalSeed::new().get();
+    folded_multiply(folded_multiply(a ^ ARBITRARY19, b) ^ c, d)
+}
+
+/// An endless, deterministic sequence of well-separated seeds derived from a
+/// single master seed.
+///
+/// Useful when you need several decorrelated hash functions, e.g. for Bloom
+/// filters or Count-Min sketches, by passing each seed to `with_seed`. The
+/// sequence is fully determined by the master seed.
+///
+/// ```rust
+/// use foldhash::SeedSequence;
+/// use foldhash::quality::FixedState;
+///
+/// let hashers: Vec<FixedState> = SeedSequence::new(42).take(4).map(FixedState::with_seed).collect();
+/// let again: Vec<u64> = SeedSequence::new(42).take(4).collect();
+/// assert_eq!(again, SeedSequence::new(42).take(4).collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeedSequence {
+    state: u64,
+}
+
+impl SeedSequence {
+    /// Creates a [`SeedSequence`] starting from the given master seed.
+    #[inline(always)]
+    pub const fn new(master: u64) -> Self {
+        Self { state: master }
+    }
+}
+
+impl Iterator for SeedSequence {
+    type Item = u64;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<u64> {
+        // A Weyl sequence (whose increment must be odd for a full period)
+        // mixed with a folded multiply, in the style of wyrand.
+        self.state = self.state.wrapping_add(ARBITRARY16 | 1);
+        Some(folded_multiply(self.state, self.state ^ ARBITRARY17))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
 pub mod fast {
     use super::*;
     use crate::fast::FoldHasher;
 
     /// A [`BuildHasher`] for [`fast::FoldHasher`]s that are randomly initialized.
+    ///
+    /// Hash maps hash a key by building a hasher, feeding it the key and
+    /// finishing it, which is exactly what [`BuildHasher::hash_one`] does. So
+    /// `hash_one` on a state returns the same hash as a map using that state
+    /// computes internally, and can be computed once and reused for several
+    /// lookups through APIs taking a precomputed hash, such as hashbrown's
+    /// `HashTable` or raw entries.
     #[derive(Copy, Clone, Debug)]
     pub struct RandomState {
         per_hasher_seed: u64,
         global_seed: global::GlobalSeed,
     }
 
+    /// Generates a fresh per-hasher seed, different for each call with high
+    /// probability.
+    pub(crate) fn random_per_hasher_seed() -> u64 {
+        // We initialize the per-hasher seed with the stack pointer to ensure
+        // different threads have different seeds, with as side benefit that
+        // stack address randomization gives us further non-determinism.
+        let mut per_hasher_seed = 0;
+        let stack_ptr = core::ptr::addr_of!(per_hasher_seed) as u64;
+        per_hasher_seed = stack_ptr;
+
+        // If we have the standard library available we use a thread-local
+        // state to ensure RandomStates are different with high probability,
+        // even if the call stack is the same.
+        #[cfg(feature = "std")]
+        {
+            use std::cell::Cell;
+            thread_local! {
+                static PER_HASHER_NONDETERMINISM: Cell<u64> = const { Cell::new(0) };
+            }
+
+            let nondeterminism = PER_HASHER_NONDETERMINISM.get();
+            per_hasher_seed = folded_multiply(per_hasher_seed, ARBITRARY1 ^ nondeterminism);
+            PER_HASHER_NONDETERMINISM.set(per_hasher_seed);
+        };
+
+        // If we don't have the standard library we instead use a global
+        // atomic instead of a thread-local state.
+        //
+        // PER_HASHER_NONDETERMINISM is loaded and updated in a racy manner,
+        // but this doesn't matter in practice - it is impossible that two
+        // different threads have the same stack location, so they'll almost
+        // surely generate different seeds, and provide a different possible
+        // update for PER_HASHER_NONDETERMINISM. If we would use a proper
+        // fetch_add atomic update then there is a larger chance of
+        // problematic contention.
+        //
+        // We use usize instead of 64-bit atomics for best platform support.
+        #[cfg(not(feature = "std"))]
+        {
+            use core::sync::atomic::{AtomicUsize, Ordering};
+            static PER_HASHER_NONDETERMINISM: AtomicUsize = AtomicUsize::new(0);
+
+            let nondeterminism = PER_HASHER_NONDETERMINISM.load(Ordering::Relaxed) as u64;
+            per_hasher_seed = folded_multiply(per_hasher_seed, ARBITRARY1 ^ nondeterminism);
+            PER_HASHER_NONDETERMINISM.store(per_hasher_seed as usize, Ordering::Relaxed);
+        }
+
+        // One extra mixing step to ensure good random bits.
+        folded_multiply(per_hasher_seed, ARBITRARY2)
+    }
+
     impl Default for RandomState {
         fn default() -> Self {
-            // We initialize the per-hasher seed with the stack pointer to ensure
-            // different threads have different seeds, with as side benefit that
-            // stack address randomization gives us further non-determinism.
-            let mut per_hasher_seed = 0;
-            let stack_ptr = core::ptr::addr_of!(per_hasher_seed) as u64;
-            per_hasher_seed = stack_ptr;
-
-            // If we have the standard library available we use a thread-local
-            // state to ensure RandomStates are different with high probability,
-            // even if the call stack is the same.
-            #[cfg(feature = "std")]
-            {
-                use std::cell::Cell;
-                thread_local! {
-                    static PER_HASHER_NONDETERMINISM: Cell<u64> = const { Cell::new(0) };
+            Self {
+                per_hasher_seed: random_per_hasher_seed(),
+                global_seed: global::GlobalSeed::new(),
+            }
+        }
+    }
+
+    impl BuildHasher for RandomState {
+        type Hasher = FoldHasher;
+
+        #[inline(always)]
+        fn build_hasher(&self) -> FoldHasher {
+            FoldHasher::with_seed(self.per_hasher_seed, self.global_seed.get())
+        }
+    }
+
+    impl RandomState {
+        /// Hashes the items of an iterator independently of their order.
+        ///
+        /// Each item is hashed on its own and the results are combined
+        /// commutatively along with the number of items. Two collections
+        /// holding the same items with the same multiplicities thus get the
+        /// same hash in any order, while duplicates don't cancel out.
+        ///
+        /// ```rust
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_unordered([1, 2, 3]), s.hash_unordered([3, 1, 2]));
+        /// assert_eq!(s.hash_unordered(["a", "b", "a"]), s.hash_unordered(["a", "a", "b"]));
+        /// assert_ne!(s.hash_unordered(["a", "a"]), s.hash_unordered(["a"]));
+        /// assert_ne!(s.hash_unordered(["a", "a"]), s.hash_unordered(["a", "b"]));
+        /// ```
+        pub fn hash_unordered<T: Hash>(&self, items: impl IntoIterator<Item = T>) -> u64 {
+            items
+                .into_iter()
+                .map(|item| self.hash_one(item))
+                .collect::<UnorderedHashFold>()
+                .finish()
+        }
+
+        /// Hashes `data` with each byte AND'ed with the corresponding byte of
+        /// `mask`, without making a masked copy.
+        ///
+        /// Two inputs hash the same if their masked bytes are equal, which is
+        /// useful for keys with "don't care" bits such as addresses under a
+        /// subnet mask. The result is not the same as [`BuildHasher::hash_one`]
+        /// on a masked copy.
+        ///
+        /// `data` and `mask` should have equal lengths, this is checked with a
+        /// debug assertion. Otherwise the bytes past the end of the shorter one
+        /// are ignored entirely.
+        ///
+        /// ```rust
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let mask = [255, 255, 255, 0];
+        /// assert_eq!(s.hash_masked(&[192, 168, 1, 10], &mask), s.hash_masked(&[192, 168, 1, 20], &mask));
+        /// assert_ne!(s.hash_masked(&[192, 168, 1, 10], &mask), s.hash_masked(&[192, 168, 2, 10], &mask));
+        /// ```
+        pub fn hash_masked(&self, data: &[u8], mask: &[u8]) -> u64 {
+            debug_assert_eq!(data.len(), mask.len());
+            let len = data.len().min(mask.len());
+            let mut hasher = self.build_hasher();
+            hasher.write_usize(len);
+            for (d, m) in data[..len].chunks(8).zip(mask[..len].chunks(8)) {
+                let mut word = [0u8; 8];
+                for i in 0..d.len() {
+                    word[i] = d[i] & m[i];
+                }
+                hasher.write_u64(u64::from_ne_bytes(word));
+            }
+            hasher.finish()
+        }
+
+        /// Hashes an IP address in a single pass over its 4 or 16 bytes.
+        ///
+        /// IPv4 addresses never hash the same as their IPv4-mapped or
+        /// IPv4-compatible IPv6 counterparts, except by chance.
+        ///
+        /// ```rust
+        /// use std::net::{IpAddr, Ipv4Addr};
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let v4 = Ipv4Addr::new(192, 168, 1, 10);
+        /// let h = s.hash_ip(&IpAddr::V4(v4));
+        /// assert_ne!(h, s.hash_ip(&IpAddr::V6(v4.to_ipv6_mapped())));
+        /// assert_ne!(h, s.hash_ip(&IpAddr::V6(v4.to_ipv6_compatible())));
+        /// ```
+        #[cfg(feature = "std")]
+        #[inline(always)]
+        pub fn hash_ip(&self, ip: &std::net::IpAddr) -> u64 {
+            use std::net::IpAddr;
+
+            let mut hasher = self.build_hasher();
+            match ip {
+                IpAddr::V4(ip) => hasher.write_u32(u32::from(*ip)),
+                IpAddr::V6(ip) => {
+                    // A lone u128 would use the same single fold as a lone
+                    // u32, the trailing write separates the two families.
+                    hasher.write_u128(u128::from(*ip));
+                    hasher.write_u8(6);
                 }
+            }
+            hasher.finish()
+        }
+
+        /// Hashes a socket address in a single pass over its address bytes and
+        /// port.
+        ///
+        /// The flow info and scope id of IPv6 socket addresses are not hashed.
+        /// IPv4 socket addresses never hash the same as their IPv4-mapped
+        /// IPv6 counterparts, except by chance.
+        ///
+        /// ```rust
+        /// use std::net::{Ipv4Addr, SocketAddr};
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let v4 = Ipv4Addr::new(10, 0, 0, 1);
+        /// let mapped = SocketAddr::new(v4.to_ipv6_mapped().into(), 80);
+        /// assert_ne!(s.hash_socket_addr(&SocketAddr::new(v4.into(), 80)), s.hash_socket_addr(&mapped));
+        /// ```
+        #[cfg(feature = "std")]
+        #[inline(always)]
+        pub fn hash_socket_addr(&self, addr: &std::net::SocketAddr) -> u64 {
+            use std::net::SocketAddr;
+
+            let mut hasher = self.build_hasher();
+            match addr {
+                SocketAddr::V4(addr) => {
+                    let ip = u32::from(*addr.ip()) as u64;
+                    hasher.write_u64(ip << 16 | addr.port() as u64);
+                }
+                SocketAddr::V6(addr) => {
+                    hasher.write_u128(u128::from(*addr.ip()));
+                    hasher.write_u16(addr.port());
+                }
+            }
+            hasher.finish()
+        }
+
+        /// Hashes the sub-slice `data[range]`, giving the same result as
+        /// [`BuildHasher::hash_one`] on `&data[range]`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the range is out of bounds of `data` or its start is
+        /// past its end, exactly like slice indexing does.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let data = b"key=value";
+        /// assert_eq!(s.hash_range(data, 4..9), s.hash_one(&b"value"[..]));
+        /// ```
+        #[inline(always)]
+        pub fn hash_range(&self, data: &[u8], range: core::ops::Range<usize>) -> u64 {
+            self.hash_one(&data[range])
+        }
 
-                let nondeterminism = PER_HASHER_NONDETERMINISM.get();
-                per_hasher_seed = folded_multiply(per_hasher_seed, ARBITRARY1 ^ nondeterminism);
-                PER_HASHER_NONDETERMINISM.set(per_hasher_seed);
+        /// Hashes a byte array, giving the same result as
+        /// [`BuildHasher::hash_one`] on it.
+        ///
+        /// Since `N` is known at compile time all length checks in the hasher
+        /// are resolved statically, e.g. a `[u8; 16]` digest or UUID goes
+        /// straight to the two-word small-input reduction.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let uuid = [7u8; 16];
+        /// assert_eq!(s.hash_array(&uuid), s.hash_one(uuid));
+        /// assert_eq!(s.hash_array(&uuid), s.hash_one(uuid.to_vec()));
+        /// ```
+        #[inline(always)]
+        pub fn hash_array<const N: usize>(&self, data: &[u8; N]) -> u64 {
+            let mut hasher = self.build_hasher();
+            hasher.write_usize(N);
+            hasher.write(data);
+            hasher.finish()
+        }
+
+        /// Hashes a [`Duration`](core::time::Duration), giving the same result
+        /// as [`BuildHasher::hash_one`] on it.
+        ///
+        /// The derived `Hash` writes the seconds and nanoseconds separately,
+        /// here they are folded together with the seed in a single reduction.
+        /// There is no equivalent for `Instant`, as its representation is
+        /// platform-specific and it can't be hashed portably.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use std::time::Duration;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let d = Duration::new(5, 30);
+        /// assert_eq!(s.hash_duration(d), s.hash_one(d));
+        /// ```
+        #[inline(always)]
+        pub fn hash_duration(&self, d: core::time::Duration) -> u64 {
+            // Writing the u64 seconds and u32 nanoseconds would fill the sponge
+            // with exactly these 128 bits before the final fold.
+            let mut hasher = self.build_hasher();
+            hasher.write_u128(((d.subsec_nanos() as u128) << 64) | d.as_secs() as u128);
+            hasher.finish()
+        }
+
+        /// Hashes a [`Primitive`](crate::Primitive) scalar, giving the same
+        /// result as [`BuildHasher::hash_one`] on it (or on its bit pattern,
+        /// for floats). Taking the value by value lets small keys stay in
+        /// registers in tight lookup loops.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_primitive(42u16), s.hash_one(42u16));
+        /// assert_eq!(s.hash_primitive(-7i64), s.hash_one(-7i64));
+        /// assert_eq!(s.hash_primitive(true), s.hash_one(true));
+        /// assert_eq!(s.hash_primitive(1.5f64), s.hash_one(1.5f64.to_bits()));
+        /// ```
+        #[inline(always)]
+        pub fn hash_primitive<T: crate::Primitive>(&self, value: T) -> u64 {
+            let mut hasher = self.build_hasher();
+            value.fold_into(&mut hasher);
+            hasher.finish()
+        }
+
+        /// Hashes a string with a fixed, documented encoding: its bytes
+        /// followed by a single `0xff` byte, with no length prefix.
+        ///
+        /// This is what the [`Hash`] implementation of `str` currently writes,
+        /// so it gives the same result as [`BuildHasher::hash_one`] on a `str`
+        /// or `String` and can be used to look up `String` keys. Unlike
+        /// [`BuildHasher::hash_one`], this encoding won't change should the
+        /// standard library ever hash strings differently (e.g. through
+        /// `Hasher::write_str`), making it suitable for cache keys.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_str("hello"), s.hash_one("hello"));
+        /// assert_eq!(s.hash_str("hello"), s.hash_one(String::from("hello")));
+        /// ```
+        #[inline(always)]
+        pub fn hash_str(&self, s: &str) -> u64 {
+            let mut hasher = self.build_hasher();
+            hasher.write(s.as_bytes());
+            hasher.write_u8(0xff);
+            hasher.finish()
+        }
+
+        /// Hashes a string whose length is already known, giving the same
+        /// result as [`BuildHasher::hash_one`] on `s` when `len == s.len()`.
+        ///
+        /// The bytes are hashed as `&s[..len]`, so when `len` is a constant
+        /// after inlining (e.g. for fixed-width tokens) the size-dependent
+        /// branches in the hasher are resolved at compile time. Passing a
+        /// wrong length panics in debug builds, and in release builds gives
+        /// an unspecified hash or panics if `len > s.len()`.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_str_with_len("token", 5), s.hash_one("token"));
+        /// ```
+        #[inline(always)]
+        pub fn hash_str_with_len(&self, s: &str, len: usize) -> u64 {
+            debug_assert_eq!(len, s.len());
+            let mut hasher = self.build_hasher();
+            hasher.write(&s.as_bytes()[..len]);
+            // Same terminator as hash_str.
+            hasher.write_u8(0xff);
+            hasher.finish()
+        }
+
+        /// Hashes the output of [`format_args!`] without allocating, giving
+        /// the same result as [`BuildHasher::hash_one`] on the formatted
+        /// string.
+        ///
+        /// If one of the formatting implementations returns an error (in
+        /// which case [`format!`] would panic) the hash is unspecified.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let (user, id) = ("alice", 42);
+        /// assert_eq!(s.hash_fmt(format_args!("{user}:{id}")), s.hash_one(format!("{user}:{id}")));
+        ///
+        /// // Long outputs are streamed, regardless of how they are split.
+        /// let long = "x".repeat(1000);
+        /// for i in [0, 1, 15, 16, 17, 64, 200, 255, 256, 257, 300, 999, 1000] {
+        ///     let (a, b) = long.split_at(i);
+        ///     assert_eq!(s.hash_fmt(format_args!("{a}{b}")), s.hash_one(&long));
+        /// }
+        /// ```
+        pub fn hash_fmt(&self, args: core::fmt::Arguments) -> u64 {
+            let mut hasher = self.build_hasher();
+            let mut stream = crate::stream::ByteStream::new(&mut hasher);
+            let _ = core::fmt::write(&mut stream, args);
+            stream.finish();
+            // Same terminator as the Hash implementation of str.
+            hasher.write_u8(0xff);
+            hasher.finish()
+        }
+
+        /// Hashes `(terms[i], docs[i])` pairs into `out[i]`, giving the same
+        /// result as [`BuildHasher::hash_one`] on the ordered tuple
+        /// `(terms[i], docs[i])`.
+        ///
+        /// Each pair fills the hasher's 128-bit buffer exactly and is folded
+        /// with a single multiplication.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `terms`, `docs` and `out` don't all have the same length.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let terms = [1, 1, 2];
+        /// let docs = [10, 20, 10];
+        /// let mut out = [0; 3];
+        /// s.hash_many_pairs(&terms, &docs, &mut out);
+        /// assert_eq!(out[1], s.hash_one((1u64, 20u64)));
+        /// ```
+        pub fn hash_many_pairs(&self, terms: &[u64], docs: &[u64], out: &mut [u64]) {
+            assert_eq!(terms.len(), docs.len());
+            assert_eq!(terms.len(), out.len());
+            for ((term, doc), out) in terms.iter().zip(docs).zip(out) {
+                let mut hasher = self.build_hasher();
+                hasher.write_u64(*term);
+                hasher.write_u64(*doc);
+                *out = hasher.finish();
+            }
+        }
+
+        /// Hashes `items[i]` into `out[i]`, giving the same result as
+        /// [`BuildHasher::hash_one`] on each item, but reusing the previous
+        /// hash for runs of equal items.
+        ///
+        /// For sorted or grouped data, e.g. an analytics column, this skips
+        /// hashing all but the first item of each run. Comparing items costs
+        /// about as much as hashing them for small keys, so this pays off
+        /// for longer keys or runs.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `items` and `out` don't have the same length.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let items = ["a", "a", "a", "b", "b", "a"];
+        /// let mut out = [0; 6];
+        /// s.hash_many_dedup(&items, &mut out);
+        /// for (item, hash) in items.iter().zip(out) {
+        ///     assert_eq!(hash, s.hash_one(item));
+        /// }
+        /// ```
+        pub fn hash_many_dedup<T: Hash + PartialEq>(&self, items: &[T], out: &mut [u64]) {
+            assert_eq!(items.len(), out.len());
+            let mut prev: Option<(&T, u64)> = None;
+            for (item, out) in items.iter().zip(out) {
+                let hash = match prev {
+                    Some((p, hash)) if p == item => hash,
+                    _ => self.hash_one(item),
+                };
+                *out = hash;
+                prev = Some((item, hash));
+            }
+        }
+
+        /// Hashes all bytes produced by a [`ByteSource`](crate::ByteSource),
+        /// giving the same result as [`BuildHasher::hash_one`] on the
+        /// concatenated chunks as a byte slice (or `Vec<u8>`).
+        ///
+        /// The chunks are streamed through the hasher without being collected,
+        /// and may be split arbitrarily.
+        pub fn hash_source<S: crate::ByteSource + ?Sized>(&self, source: &mut S) -> u64 {
+            let mut hasher = self.build_hasher();
+            let mut stream = crate::stream::ByteStream::new(&mut hasher);
+            let mut len = 0;
+            while let Some(chunk) = source.next_chunk() {
+                len += chunk.len();
+                stream.write(chunk);
+            }
+            stream.finish();
+            // Writing bytes never touches the buffered integers, so the length
+            // prefix of the slice may as well come last.
+            hasher.write_usize(len);
+            hasher.finish()
+        }
+
+        /// Hashes several byte slices as if they were concatenated, giving the
+        /// same result as [`BuildHasher::hash_one`] on the concatenation as a
+        /// byte slice, regardless of where the parts are split.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let key = s.hash_concat(&[b"user/", b"42", b"/profile"]);
+        /// assert_eq!(key, s.hash_one(&b"user/42/profile"[..]));
+        /// assert_eq!(key, s.hash_concat(&[b"user/42/", b"profile"]));
+        /// ```
+        pub fn hash_concat(&self, parts: &[&[u8]]) -> u64 {
+            let mut hasher = self.build_hasher();
+            let mut stream = crate::stream::ByteStream::new(&mut hasher);
+            let mut len = 0;
+            for part in parts {
+                len += part.len();
+                stream.write(part);
+            }
+            stream.finish();
+            // See hash_source.
+            hasher.write_usize(len);
+            hasher.finish()
+        }
+
+        /// Hashes a value like [`BuildHasher::hash_one`], but returns a
+        /// [`NonZeroU64`](core::num::NonZeroU64) so the hash can be stored in
+        /// an `Option` without extra space.
+        ///
+        /// A hash of zero is replaced by one, every other hash is returned
+        /// unchanged. So the only cost is that the values hashing to zero
+        /// and one collide, which for a well-distributed hash happens with
+        /// probability 2^-64.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_nonzero(&"key").get(), s.hash_one("key"));
+        /// assert_eq!(size_of::<Option<std::num::NonZeroU64>>(), 8);
+        /// ```
+        #[inline(always)]
+        pub fn hash_nonzero<T: Hash + ?Sized>(&self, value: &T) -> core::num::NonZeroU64 {
+            let hash = self.hash_one(value);
+            core::num::NonZeroU64::new(hash).unwrap_or(core::num::NonZeroU64::MIN)
+        }
+
+        /// Hashes a sequence of values of different types, without
+        /// instantiating the hashing code for each combination of types.
+        ///
+        /// The number of items is hashed first, so sequences of different
+        /// lengths differ, followed by each item. This gives the same result
+        /// as [`BuildHasher::hash_one`] on a slice of the items if they have
+        /// the same type, except for slices of integers, which `Hash` hashes
+        /// as a single block of bytes.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let hash = s.hash_dyn_slice(&[&"add", &1u32, &(2u8, 'x')]);
+        /// assert_ne!(hash, s.hash_dyn_slice(&[&"add", &1u32]));
+        /// assert_eq!(s.hash_dyn_slice(&[&"a", &"b"]), s.hash_one(&["a", "b"][..]));
+        /// ```
+        pub fn hash_dyn_slice(&self, items: &[&dyn crate::ErasedHash]) -> u64 {
+            let mut hasher = self.build_hasher();
+            hasher.write_usize(items.len());
+            for item in items {
+                item.erased_hash(&mut hasher);
+            }
+            hasher.finish()
+        }
+
+        /// Hashes a [`Cow`](alloc::borrow::Cow) byte slice, giving the same
+        /// result as [`BuildHasher::hash_one`] on `c.as_ref()`.
+        // Taking the Cow itself lets Cow-keyed call sites pass their keys as-is.
+        #[allow(clippy::ptr_arg)]
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        pub fn hash_cow_bytes(&self, c: &alloc::borrow::Cow<[u8]>) -> u64 {
+            self.hash_one(c.as_ref())
+        }
+
+        /// Hashes a [`Cow`](alloc::borrow::Cow) string, giving the same result
+        /// as [`BuildHasher::hash_one`] on `c.as_ref()`.
+        ///
+        /// ```rust
+        /// use std::borrow::Cow;
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let borrowed: Cow<str> = Cow::Borrowed("key");
+        /// let owned: Cow<str> = Cow::Owned("key".to_string());
+        /// assert_eq!(s.hash_cow_str(&borrowed), s.hash_one("key"));
+        /// assert_eq!(s.hash_cow_str(&owned), s.hash_one("key"));
+        /// ```
+        // Taking the Cow itself lets Cow-keyed call sites pass their keys as-is.
+        #[allow(clippy::ptr_arg)]
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        pub fn hash_cow_str(&self, c: &alloc::borrow::Cow<str>) -> u64 {
+            self.hash_one(c.as_ref())
+        }
+
+        /// Hashes a [`SystemTime`](std::time::SystemTime) by its distance to
+        /// the [`UNIX_EPOCH`](std::time::UNIX_EPOCH).
+        ///
+        /// Times at or after the epoch hash exactly like
+        /// [`hash_duration`](Self::hash_duration) on their duration since the
+        /// epoch. Times before the epoch hash their duration until the epoch
+        /// with a sign bit set in the (otherwise at most 30-bit) nanoseconds,
+        /// so they never collide with times after it.
+        ///
+        /// ```rust
+        /// use std::time::{Duration, UNIX_EPOCH};
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let d = Duration::from_secs(1_700_000_000);
+        /// assert_eq!(s.hash_system_time(UNIX_EPOCH + d), s.hash_duration(d));
+        /// assert_ne!(s.hash_system_time(UNIX_EPOCH - d), s.hash_duration(d));
+        /// ```
+        #[cfg(feature = "std")]
+        #[inline(always)]
+        pub fn hash_system_time(&self, t: std::time::SystemTime) -> u64 {
+            let (d, sign) = match t.duration_since(std::time::UNIX_EPOCH) {
+                Ok(d) => (d, 0),
+                Err(e) => (e.duration(), 1 << 31),
             };
+            let mut hasher = self.build_hasher();
+            hasher.write_u128((((d.subsec_nanos() | sign) as u128) << 64) | d.as_secs() as u128);
+            hasher.finish()
+        }
 
-            // If we don't have the standard library we instead use a global
-            // atomic instead of a thread-local state.
-            //
-            // PER_HASHER_NONDETERMINISM is loaded and updated in a racy manner,
-            // but this doesn't matter in practice - it is impossible that two
-            // different threads have the same stack location, so they'll almost
-            // surely generate different seeds, and provide a different possible
-            // update for PER_HASHER_NONDETERMINISM. If we would use a proper
-            // fetch_add atomic update then there is a larger chance of
-            // problematic contention.
-            //
-            // We use usize instead of 64-bit atomics for best platform support.
-            #[cfg(not(feature = "std"))]
-            {
-                use core::sync::atomic::{AtomicUsize, Ordering};
-                static PER_HASHER_NONDETERMINISM: AtomicUsize = AtomicUsize::new(0);
-
-                let nondeterminism = PER_HASHER_NONDETERMINISM.load(Ordering::Relaxed) as u64;
-                per_hasher_seed = folded_multiply(per_hasher_seed, ARBITRARY1 ^ nondeterminism);
-                PER_HASHER_NONDETERMINISM.store(per_hasher_seed as usize, Ordering::Relaxed);
+        /// Hashes a value like [`BuildHasher::hash_one`], also returning
+        /// whether the input looked non-degenerate.
+        ///
+        /// In debug builds the value is additionally fed through a cheap
+        /// check of the bytes its [`Hash`] implementation writes. The flag is
+        /// `false` when those bytes are too few (zero or one byte) or almost
+        /// uniform: a single repeated byte value, or at most two values in 16
+        /// or more bytes, e.g. an all-zero key or a zeroed buffer with its
+        /// length prefix. Such keys are fine to hash, but many of them in one
+        /// map usually point at a bug in how keys are built. In release builds
+        /// the check is skipped and the flag is always `true`.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_one_checked("hello").0, s.hash_one("hello"));
+        /// assert!(s.hash_one_checked("hello").1);
+        /// ```
+        #[inline(always)]
+        pub fn hash_one_checked<T: Hash>(&self, value: T) -> (u64, bool) {
+            #[cfg(debug_assertions)]
+            let ok = {
+                let mut check = DegeneracyCheck::default();
+                value.hash(&mut check);
+                !check.is_degenerate()
+            };
+            #[cfg(not(debug_assertions))]
+            let ok = true;
+
+            (self.hash_one(value), ok)
+        }
+
+        /// Hashes a byte slice in windows of `chunk` bytes, giving the same
+        /// result as [`BuildHasher::hash_one`] on the whole slice for any
+        /// `chunk`.
+        ///
+        /// This allows tuning how much of a large buffer is processed at once,
+        /// e.g. to match a prefetching or streaming strategy, without changing
+        /// the hash.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `chunk` is zero.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let data = vec![7u8; 100_000];
+        /// assert_eq!(s.hash_bytes_chunked(&data, 4096), s.hash_one(&data[..]));
+        /// assert_eq!(s.hash_bytes_chunked(&data, 13), s.hash_one(&data[..]));
+        /// ```
+        pub fn hash_bytes_chunked(&self, data: &[u8], chunk: usize) -> u64 {
+            let mut hasher = self.build_hasher();
+            let mut stream = crate::stream::ByteStream::new(&mut hasher);
+            for window in data.chunks(chunk) {
+                stream.write(window);
             }
+            stream.finish();
+            // See hash_source.
+            hasher.write_usize(data.len());
+            hasher.finish()
+        }
+
+        /// Hashes a value into a reused scratch hasher, giving the same result
+        /// as [`BuildHasher::hash_one`].
+        ///
+        /// `scratch` is reset to the initial state of this [`RandomState`]
+        /// before hashing, so it may hold any previous state. Note that
+        /// building a [`FoldHasher`] only copies the seeds without mixing, so
+        /// this is mostly useful for loops that keep a hasher around anyway.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let mut scratch = s.build_hasher();
+        /// for key in ["a", "b", "c"] {
+        ///     assert_eq!(s.hash_one_reuse(&mut scratch, key), s.hash_one(key));
+        /// }
+        /// ```
+        #[inline(always)]
+        pub fn hash_one_reuse<T: Hash>(&self, scratch: &mut FoldHasher, value: T) -> u64 {
+            *scratch = self.build_hasher();
+            value.hash(scratch);
+            scratch.finish()
+        }
+
+        /// Hashes the first `len` bytes of a partially initialized buffer,
+        /// giving the same result as [`BuildHasher::hash_one`] on those bytes
+        /// as a `&[u8]`.
+        ///
+        /// # Safety
+        ///
+        /// The first `len` bytes of `buf` must be initialized.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `len > buf.len()`, in all builds.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use std::mem::MaybeUninit;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let mut buf = [MaybeUninit::<u8>::uninit(); 64];
+        /// for (dst, src) in buf.iter_mut().zip(b"hello") {
+        ///     dst.write(*src);
+        /// }
+        ///
+        /// let s = RandomState::default();
+        /// // SAFETY: we initialized the first 5 bytes above.
+        /// let hash = unsafe { s.hash_init_prefix(&buf, 5) };
+        /// assert_eq!(hash, s.hash_one(&b"hello"[..]));
+        /// ```
+        #[inline(always)]
+        pub unsafe fn hash_init_prefix(
+            &self,
+            buf: &[core::mem::MaybeUninit<u8>],
+            len: usize,
+        ) -> u64 {
+            let prefix = &buf[..len];
+            // SAFETY: MaybeUninit<u8> has the same layout as u8 and the caller
+            // guarantees these bytes are initialized.
+            let bytes = unsafe { core::slice::from_raw_parts(prefix.as_ptr().cast::<u8>(), len) };
+            self.hash_one(bytes)
+        }
+
+        /// Hashes a zero-padded byte field with its trailing zero bytes
+        /// removed, giving the same result as [`BuildHasher::hash_one`] on the
+        /// trimmed bytes.
+        ///
+        /// **This changes equality semantics**: fields differing only in the
+        /// number of trailing zeros hash equally, so they must also compare
+        /// equal wherever these hashes are used, e.g. for fixed-width C-string
+        /// style fields. An all-zero field hashes like the empty slice.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_trimmed(b"abc\0\0"), s.hash_trimmed(b"abc"));
+        /// assert_eq!(s.hash_trimmed(b"abc"), s.hash_one(&b"abc"[..]));
+        /// assert_eq!(s.hash_trimmed(&[0; 16]), s.hash_one(&b""[..]));
+        /// assert_ne!(s.hash_trimmed(b"a\0c"), s.hash_trimmed(b"a"));
+        /// ```
+        #[inline]
+        pub fn hash_trimmed(&self, data: &[u8]) -> u64 {
+            let len = data.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+            self.hash_one(&data[..len])
+        }
+
+        /// Hashes a value to a pseudo-random `f64` uniformly distributed in
+        /// `[0, 1)`, e.g. for deterministic sampling keyed on item identity.
+        ///
+        /// The top 53 bits of [`BuildHasher::hash_one`] are scaled by `2^-53`,
+        /// so every output is exactly representable and `1.0` is never
+        /// returned. The result only depends on the state and the value.
+        ///
+        /// ```rust
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let x = s.hash_to_unit_f64("item-42");
+        /// assert!((0.0..1.0).contains(&x));
+        /// assert_eq!(x, s.hash_to_unit_f64("item-42"));
+        /// ```
+        #[inline(always)]
+        pub fn hash_to_unit_f64<T: Hash>(&self, value: T) -> f64 {
+            (self.hash_one(value) >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+        }
+
+        /// Returns a hasher for keys, in a separate domain from
+        /// [`value_hasher`](Self::value_hasher).
+        ///
+        /// Both hashers derive their own per-hasher seed from this state, so
+        /// they are as unrelated as the hashers of two independent
+        /// [`RandomState`]s, and a value equal to a key doesn't hash equally
+        /// in both roles. Note that neither matches [`BuildHasher::build_hasher`].
+        ///
+        /// ```rust
+        /// use std::hash::{Hash, Hasher};
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let mut key = s.key_hasher();
+        /// let mut value = s.value_hasher();
+        /// "same".hash(&mut key);
+        /// "same".hash(&mut value);
+        /// assert_ne!(key.finish(), value.finish());
+        /// ```
+        #[inline(always)]
+        pub fn key_hasher(&self) -> FoldHasher {
+            self.domain_hasher(ARBITRARY23)
+        }
+
+        /// Returns a hasher for values, in a separate domain from
+        /// [`key_hasher`](Self::key_hasher).
+        #[inline(always)]
+        pub fn value_hasher(&self) -> FoldHasher {
+            self.domain_hasher(ARBITRARY24)
+        }
+
+        #[inline(always)]
+        fn domain_hasher(&self, domain: u64) -> FoldHasher {
+            let per_hasher_seed = folded_multiply(self.per_hasher_seed ^ domain, ARBITRARY25);
+            FoldHasher::with_seed(per_hasher_seed, self.global_seed.get())
+        }
+
+        /// Hashes a value salted with a compile-time version, giving the same
+        /// result as [`BuildHasher::hash_one`] on the tuple `(V, value)`.
+        ///
+        /// Bumping `V` changes every hash, e.g. to invalidate all entries of
+        /// a cache at once, without touching how keys are built.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// assert_eq!(s.hash_versioned::<3, _>("key"), s.hash_one((3u64, "key")));
+        /// assert_ne!(s.hash_versioned::<3, _>("key"), s.hash_versioned::<4, _>("key"));
+        /// ```
+        #[inline(always)]
+        pub fn hash_versioned<const V: u64, T: Hash>(&self, value: T) -> u64 {
+            let mut hasher = self.build_hasher();
+            hasher.write_u64(V);
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        /// Hashes a `NonZero` integer, giving the same result as
+        /// [`BuildHasher::hash_one`] on the underlying integer.
+        ///
+        /// The niche of the `NonZero` type plays no part: the value is folded
+        /// with the same single write as the plain integer, so `NonZero` and
+        /// plain keys can be looked up interchangeably.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use std::num::{NonZeroU32, NonZeroU64, NonZeroUsize};
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let a = NonZeroU32::new(7).unwrap();
+        /// let b = NonZeroU64::new(7).unwrap();
+        /// let c = NonZeroUsize::new(7).unwrap();
+        /// assert_eq!(s.hash_nonzero_in(a), s.hash_one(a.get()));
+        /// assert_eq!(s.hash_nonzero_in(b), s.hash_one(b.get()));
+        /// assert_eq!(s.hash_nonzero_in(c), s.hash_one(c.get()));
+        /// assert_eq!(s.hash_nonzero_in(a), s.hash_one(a));
+        /// ```
+        #[inline(always)]
+        pub fn hash_nonzero_in<T: crate::NonZeroPrimitive>(&self, value: T) -> u64 {
+            self.hash_primitive(value)
+        }
+
+        /// Hashes key-value entries independently of their order, as a
+        /// fingerprint of an association list or small map.
+        ///
+        /// This is [`hash_unordered`](Self::hash_unordered) over the `(key,
+        /// value)` pairs: the entries are treated as a multiset, so the count
+        /// is included and duplicate entries don't cancel out.
+        ///
+        /// ```rust
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let a = [("x", 1), ("y", 2)];
+        /// let b = [("y", 2), ("x", 1)];
+        /// assert_eq!(s.hash_entries(&a), s.hash_entries(&b));
+        /// assert_ne!(s.hash_entries(&a), s.hash_entries(&[("x", 2), ("y", 1)]));
+        /// ```
+        #[inline]
+        pub fn hash_entries<K: Hash, V: Hash>(&self, entries: &[(K, V)]) -> u64 {
+            self.hash_unordered(entries)
+        }
+
+        /// Hashes a value tagged with a type name, giving the same result as
+        /// [`BuildHasher::hash_one`] on the tuple `(type_name, value)`.
+        ///
+        /// Passing [`core::any::type_name`] makes renaming or moving a type
+        /// change the hashes of its values, e.g. to invalidate an on-disk
+        /// cache. Note that `type_name` output is not guaranteed to be stable
+        /// across compiler versions, so a toolchain update may invalidate the
+        /// cache as well.
+        ///
+        /// ```rust
+        /// use std::any::type_name;
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// #[derive(Hash)]
+        /// struct Config(u32);
+        ///
+        /// let s = RandomState::default();
+        /// let hash = s.hash_named(type_name::<Config>(), &Config(1));
+        /// assert_eq!(hash, s.hash_one((type_name::<Config>(), &Config(1))));
+        /// ```
+        #[inline(always)]
+        pub fn hash_named<T: Hash>(&self, type_name: &str, value: T) -> u64 {
+            let mut hasher = self.build_hasher();
+            type_name.hash(&mut hasher);
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        /// Derives `k` deterministic [`FixedState`]s with decorrelated seeds,
+        /// as independent hash functions for MinHash, LSH or Bloom filters.
+        ///
+        /// The seeds are drawn from a [`SeedSequence`] started at this
+        /// state's per-hasher seed, so the family differs between
+        /// `RandomState`s but is stable for clones of the same one.
+        ///
+        /// Like every [`FixedState`], the members use foldhash's fixed global
+        /// seed rather than the process-wide one of this state, so they only
+        /// depend on its per-hasher seed. In particular, a global seed
+        /// imported with [`RunReproducibleState::import_global_seed`] doesn't
+        /// carry over. Build a [`SeedableRandomState`] per member with
+        /// [`SeedableRandomState::with_seed`] if the global seed must apply.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::RandomState;
+        ///
+        /// let s = RandomState::default();
+        /// let family = s.family(4);
+        /// let shingle = "the quick";
+        /// let hashes: Vec<u64> = family.iter().map(|f| f.hash_one(shingle)).collect();
+        /// assert_eq!(hashes.len(), 4);
+        /// assert_eq!(s.family(4)[2].hash_one(shingle), hashes[2]);
+        /// ```
+        #[cfg(feature = "alloc")]
+        pub fn family(&self, k: usize) -> alloc::vec::Vec<FixedState> {
+            SeedSequence::new(self.per_hasher_seed)
+                .take(k)
+                .map(FixedState::with_seed)
+                .collect()
+        }
+    }
+
+    /// Hashes a byte slice, giving the same result as [`BuildHasher::hash_one`]
+    /// on `data`.
+    ///
+    /// Unlike `hash_one` this is not generic, so all call sites share a single
+    /// copy of the hashing code. Hash `&str` keys through
+    /// [`str::as_bytes`] only if all other sites do the same, as the result
+    /// differs from hashing the `&str` itself.
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasher;
+    /// use foldhash::fast::{hash_one_bytes, RandomState};
+    ///
+    /// let s = RandomState::default();
+    /// assert_eq!(hash_one_bytes(&s, b"hello"), s.hash_one(&b"hello"[..]));
+    /// ```
+    pub fn hash_one_bytes(state: &RandomState, data: &[u8]) -> u64 {
+        state.hash_one(data)
+    }
+
+    /// Hashes a value with explicitly given seeds, giving the same result as
+    /// [`BuildHasher::hash_one`] on a [`SeedableRandomState`] with those seeds.
+    ///
+    /// This skips the check that the global seed is initialized which
+    /// [`RandomState`] does when hashing, for hot loops sweeping many
+    /// per-hasher seeds with one global seed resolved up front.
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasher;
+    /// use foldhash::fast::{hash_one_with_seeds, RunReproducibleState, SeedableRandomState};
+    ///
+    /// let global = RunReproducibleState::export_global_seed();
+    /// for per_hasher in 0..10 {
+    ///     let state = SeedableRandomState::with_seed(per_hasher, global);
+    ///     assert_eq!(hash_one_with_seeds(per_hasher, &global, "key"), state.hash_one("key"));
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn hash_one_with_seeds<T: Hash>(
+        per_hasher_seed: u64,
+        global_seed: &[u64; 4],
+        value: T,
+    ) -> u64 {
+        let mut hasher = FoldHasher::with_seed(per_hasher_seed, global_seed);
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// A [`Hasher`] which only inspects the bytes written to it, used by
+    /// [`RandomState::hash_one_checked`].
+    #[cfg(debug_assertions)]
+    #[derive(Default)]
+    struct DegeneracyCheck {
+        len: usize,
+        seen: [u64; 4],
+    }
+
+    #[cfg(debug_assertions)]
+    impl DegeneracyCheck {
+        fn is_degenerate(&self) -> bool {
+            let distinct: u32 = self.seen.iter().map(|w| w.count_ones()).sum();
+            // Long inputs are allowed two distinct values as length prefixes
+            // add a byte value to otherwise uniform data.
+            self.len <= 1 || distinct <= 1 || (self.len >= 16 && distinct <= 2)
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    impl Hasher for DegeneracyCheck {
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.seen[b as usize / 64] |= 1 << (b % 64);
+            }
+            self.len += bytes.len();
+        }
+
+        fn finish(&self) -> u64 {
+            0
+        }
+    }
+
+    /// A [`BuildHasher`] for [`fast::FoldHasher`]s with an explicit seed.
+    ///
+    /// By default it is randomly initialized exactly like [`RandomState`], but
+    /// its seeds can be extracted and pinned again with
+    /// [`with_seed`](Self::with_seed) to reproduce the same hashes, e.g. to
+    /// reproduce a bug. It is larger than [`RandomState`] as it stores the
+    /// global seed inline, which in turn makes hash maps using it larger.
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasher;
+    /// use foldhash::fast::SeedableRandomState;
+    ///
+    /// let random = SeedableRandomState::default();
+    /// let pinned = SeedableRandomState::with_seed(random.per_hasher_seed(), random.global_seed());
+    /// assert_eq!(random.hash_one("hello"), pinned.hash_one("hello"));
+    /// ```
+    #[derive(Copy, Clone, Debug)]
+    pub struct SeedableRandomState {
+        per_hasher_seed: u64,
+        global_seed: [u64; 4],
+        // Whether the seeds were passed in by the caller, which makes the
+        // hashes deterministic.
+        explicitly_seeded: bool,
+    }
 
-            // One extra mixing step to ensure good random bits.
-            per_hasher_seed = folded_multiply(per_hasher_seed, ARBITRARY2);
+    impl SeedableRandomState {
+        /// Creates a [`SeedableRandomState`] with the given seeds.
+        ///
+        /// The global seed words are XOR'ed with the input while hashing, and
+        /// zeroes are a weak point for the multiply-mix. The global seeds
+        /// generated by foldhash always have their top, middle and bottom bit
+        /// set to avoid this, hand-picked seeds should do the same.
+        ///
+        /// The resulting state counts as deterministic for
+        /// [`FoldBuildHasher::is_deterministic`](crate::FoldBuildHasher::is_deterministic).
+        #[inline(always)]
+        pub const fn with_seed(per_hasher_seed: u64, global_seed: [u64; 4]) -> Self {
+            Self {
+                per_hasher_seed,
+                global_seed,
+                explicitly_seeded: true,
+            }
+        }
 
+        /// Like [`with_seed`](Self::with_seed), for seeds freshly drawn from
+        /// foldhash's own randomness rather than given by the caller.
+        #[inline(always)]
+        pub(crate) const fn with_random_seed(per_hasher_seed: u64, global_seed: [u64; 4]) -> Self {
             Self {
                 per_hasher_seed,
+                global_seed,
+                explicitly_seeded: false,
+            }
+        }
+
+        /// Creates a [`SeedableRandomState`] from the four keys of an aHash
+        /// `RandomState::with_seeds`, as a migration convenience.
+        ///
+        /// The keys are deterministically mixed into foldhash's seeds, so
+        /// code configured with aHash keys can switch to foldhash with the
+        /// same configuration, e.g. to compare both side by side. The hashes
+        /// are unrelated to the ones aHash computes with those keys.
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::fast::SeedableRandomState;
+        ///
+        /// let keys = [1, 2, 3, 4];
+        /// let a = SeedableRandomState::from_ahash_keys(keys);
+        /// let b = SeedableRandomState::from_ahash_keys(keys);
+        /// assert_eq!(a.hash_one("key"), b.hash_one("key"));
+        /// assert_ne!(a.hash_one("key"), SeedableRandomState::from_ahash_keys([1, 2, 3, 5]).hash_one("key"));
+        /// ```
+        pub const fn from_ahash_keys(keys: [u64; 4]) -> Self {
+            // Force the same bits on as in foldhash's own global seeds.
+            const fn mix(key: u64) -> u64 {
+                folded_multiply(key ^ ARBITRARY21, ARBITRARY22) | FORCED_ONES
+            }
+            Self {
+                per_hasher_seed: folded_multiply(
+                    keys[0] ^ keys[2],
+                    keys[1] ^ keys[3] ^ ARBITRARY22,
+                ),
+                global_seed: [mix(keys[0]), mix(keys[1]), mix(keys[2]), mix(keys[3])],
+                explicitly_seeded: true,
+            }
+        }
+
+        /// Returns the per-hasher seed of this state.
+        #[inline(always)]
+        pub const fn per_hasher_seed(&self) -> u64 {
+            self.per_hasher_seed
+        }
+
+        /// Returns the global seed of this state.
+        #[inline(always)]
+        pub const fn global_seed(&self) -> [u64; 4] {
+            self.global_seed
+        }
+
+        #[inline(always)]
+        pub(crate) const fn is_explicitly_seeded(&self) -> bool {
+            self.explicitly_seeded
+        }
+    }
+
+    impl Default for SeedableRandomState {
+        fn default() -> Self {
+            Self::with_random_seed(random_per_hasher_seed(), *global::GlobalSeed::new().get())
+        }
+    }
+
+    impl BuildHasher for SeedableRandomState {
+        type Hasher = FoldHasher;
+
+        #[inline(always)]
+        fn build_hasher(&self) -> FoldHasher {
+            FoldHasher::with_seed(self.per_hasher_seed, &self.global_seed)
+        }
+    }
+
+    /// A [`BuildHasher`] for [`fast::FoldHasher`]s that differ per map, but can
+    /// be reproduced in a later run of the program.
+    ///
+    /// Each state gets its own random per-hasher seed exactly like
+    /// [`RandomState`], so hash maps using it have different iteration orders.
+    /// The global seed is random as well, but it can be captured with
+    /// [`export_global_seed`](Self::export_global_seed) and installed at the
+    /// start of a later run with [`import_global_seed`](Self::import_global_seed).
+    /// To reproduce a particular map, log its
+    /// [`per_hasher_seed`](Self::per_hasher_seed) too and pass both seeds to
+    /// [`SeedableRandomState::with_seed`].
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasher;
+    /// use foldhash::fast::{RunReproducibleState, SeedableRandomState};
+    ///
+    /// let seed = RunReproducibleState::export_global_seed();
+    /// // Importing only succeeds before the global seed is first used, or
+    /// // if it is the same seed.
+    /// assert!(RunReproducibleState::import_global_seed(seed));
+    ///
+    /// let a = RunReproducibleState::default();
+    /// let b = RunReproducibleState::default();
+    /// assert_ne!(a.hash_one(42), b.hash_one(42));
+    ///
+    /// // In a later run, after importing the same global seed.
+    /// let again = SeedableRandomState::with_seed(a.per_hasher_seed(), seed);
+    /// assert_eq!(again.hash_one(42), a.hash_one(42));
+    /// ```
+    #[derive(Copy, Clone, Debug)]
+    pub struct RunReproducibleState {
+        per_hasher_seed: u64,
+        global_seed: global::GlobalSeed,
+    }
+
+    impl RunReproducibleState {
+        /// Returns the global seed, initializing it randomly if it wasn't yet.
+        ///
+        /// The global seed is shared with [`RandomState`].
+        pub fn export_global_seed() -> [u64; 4] {
+            *global::GlobalSeed::new().get()
+        }
+
+        /// Sets the global seed to a value previously returned by
+        /// [`export_global_seed`](Self::export_global_seed).
+        ///
+        /// This only takes effect if no randomly seeded state was created
+        /// yet in this process, so it should be called at the very start of
+        /// the program. Returns whether the global seed now equals `seed`.
+        pub fn import_global_seed(seed: [u64; 4]) -> bool {
+            global::GlobalSeed::init_with(seed)
+        }
+
+        /// Returns the per-hasher seed of this state.
+        #[inline(always)]
+        pub fn per_hasher_seed(&self) -> u64 {
+            self.per_hasher_seed
+        }
+    }
+
+    impl Default for RunReproducibleState {
+        fn default() -> Self {
+            Self {
+                per_hasher_seed: random_per_hasher_seed(),
                 global_seed: global::GlobalSeed::new(),
             }
         }
     }
 
-    impl BuildHasher for RandomState {
+    impl BuildHasher for RunReproducibleState {
         type Hasher = FoldHasher;
 
         #[inline(always)]
@@ -88,6 +1362,144 @@ pub mod fast {
         }
     }
 
+    /// A [`BuildHasher`] for [`CompactFoldHasher`]s that are randomly
+    /// initialized like [`RandomState`].
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasher;
+    /// use foldhash::fast::{CompactFoldHasher, CompactState};
+    ///
+    /// assert_eq!(std::mem::size_of::<CompactFoldHasher>(), 8);
+    /// let s = CompactState::default();
+    /// assert_eq!(s.hash_one("key"), s.hash_one("key"));
+    /// ```
+    #[derive(Copy, Clone, Debug)]
+    pub struct CompactState {
+        per_hasher_seed: u64,
+        global_seed: global::GlobalSeed,
+    }
+
+    impl Default for CompactState {
+        fn default() -> Self {
+            Self {
+                per_hasher_seed: random_per_hasher_seed(),
+                global_seed: global::GlobalSeed::new(),
+            }
+        }
+    }
+
+    impl BuildHasher for CompactState {
+        type Hasher = CompactFoldHasher;
+
+        #[inline(always)]
+        fn build_hasher(&self) -> CompactFoldHasher {
+            CompactFoldHasher {
+                accumulator: self.per_hasher_seed,
+                global_seed: self.global_seed,
+            }
+        }
+    }
+
+    /// A [`Hasher`] implementing foldhash whose whole state is a single
+    /// `u64`, for keeping many hashers in flight at once.
+    ///
+    /// [`FoldHasher`] buffers up to 128 bits of integer writes so it can fold
+    /// two of them with one multiplication, which takes another 17 bytes of
+    /// state, plus copies of the global seed. This hasher instead folds every
+    /// integer write immediately and reads the global seed from its static
+    /// storage. Keys consisting of several integers are therefore slower to
+    /// hash, and integers of different widths but with the same value are
+    /// written identically. Byte slices are hashed exactly like
+    /// [`FoldHasher`] does.
+    ///
+    /// It can't be created directly, see [`CompactState`].
+    #[derive(Copy, Clone, Debug)]
+    pub struct CompactFoldHasher {
+        accumulator: u64,
+        global_seed: global::GlobalSeed,
+    }
+
+    impl CompactFoldHasher {
+        #[inline(always)]
+        fn fold(&mut self, lo: u64, hi: u64) {
+            let fold_seed = self.global_seed.get()[0];
+            self.accumulator = folded_multiply(lo ^ self.accumulator, hi ^ fold_seed);
+        }
+    }
+
+    impl Hasher for CompactFoldHasher {
+        #[inline(always)]
+        fn write(&mut self, bytes: &[u8]) {
+            let mut hasher = FoldHasher::with_seed(self.accumulator, self.global_seed.get());
+            hasher.write(bytes);
+            self.accumulator = hasher.accumulator;
+        }
+
+        #[inline(always)]
+        fn write_u8(&mut self, i: u8) {
+            self.fold(i as u64, 0);
+        }
+
+        #[inline(always)]
+        fn write_u16(&mut self, i: u16) {
+            self.fold(i as u64, 0);
+        }
+
+        #[inline(always)]
+        fn write_u32(&mut self, i: u32) {
+            self.fold(i as u64, 0);
+        }
+
+        #[inline(always)]
+        fn write_u64(&mut self, i: u64) {
+            self.fold(i, 0);
+        }
+
+        #[inline(always)]
+        fn write_u128(&mut self, i: u128) {
+            self.fold(i as u64, (i >> 64) as u64);
+        }
+
+        #[inline(always)]
+        fn write_usize(&mut self, i: usize) {
+            self.fold(i as u64, 0);
+        }
+
+        #[inline(always)]
+        fn finish(&self) -> u64 {
+            self.accumulator
+        }
+    }
+
+    /// A zero-sized [`BuildHasher`] for [`fast::FoldHasher`]s seeded with the
+    /// process-global random seed.
+    ///
+    /// Like [`BuildHasherDefault`](core::hash::BuildHasherDefault) it stores
+    /// nothing, so maps using it are as small as with [`FixedState`], but its
+    /// hashes still differ between runs of the program. Unlike [`RandomState`]
+    /// there is no per-map seed: all maps in a process using [`GlobalDefault`]
+    /// hash identically, so moving entries between two such maps in iteration
+    /// order can degrade them.
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasher;
+    /// use foldhash::GlobalDefault;
+    ///
+    /// assert_eq!(std::mem::size_of::<GlobalDefault>(), 0);
+    /// assert_eq!(GlobalDefault.hash_one(42), GlobalDefault.hash_one(42));
+    /// ```
+    #[derive(Copy, Clone, Default, Debug)]
+    pub struct GlobalDefault;
+
+    impl BuildHasher for GlobalDefault {
+        type Hasher = FoldHasher;
+
+        #[inline(always)]
+        fn build_hasher(&self) -> FoldHasher {
+            FoldHasher::with_seed(ARBITRARY3, global::GlobalSeed::new().get())
+        }
+    }
+
     /// A [`BuildHasher`] for [`fast::FoldHasher`]s that all have the same fixed seed.
     ///
     /// Not recommended unless you absolutely need determinism.
@@ -105,6 +1517,27 @@ pub mod fast {
                 per_hasher_seed: seed ^ ARBITRARY3,
             }
         }
+
+        /// Creates a [`FoldHasher`] in a `const` context, identical to the one
+        /// returned by [`BuildHasher::build_hasher`].
+        ///
+        /// ```rust
+        /// use std::hash::{BuildHasher, Hasher};
+        /// use foldhash::fast::{FixedState, FoldHasher};
+        ///
+        /// const STATE: FixedState = FixedState::with_seed(42);
+        /// const HASHER: FoldHasher = STATE.build_hasher_const();
+        ///
+        /// let mut a = HASHER.clone();
+        /// let mut b = STATE.build_hasher();
+        /// a.write_u64(1337);
+        /// b.write_u64(1337);
+        /// assert_eq!(a.finish(), b.finish());
+        /// ```
+        #[inline(always)]
+        pub const fn build_hasher_const(&self) -> FoldHasher {
+            FoldHasher::with_seed(self.per_hasher_seed, &FIXED_GLOBAL_SEED)
+        }
     }
 
     impl Default for FixedState {
@@ -121,7 +1554,51 @@ pub mod fast {
 
         #[inline(always)]
         fn build_hasher(&self) -> FoldHasher {
-            FoldHasher::with_seed(self.per_hasher_seed, &FIXED_GLOBAL_SEED)
+            self.build_hasher_const()
+        }
+    }
+
+    impl BuildHasher for &FixedState {
+        type Hasher = FoldHasher;
+
+        #[inline(always)]
+        fn build_hasher(&self) -> FoldHasher {
+            self.build_hasher_const()
+        }
+    }
+
+    /// A zero-sized [`BuildHasher`] for [`fast::FoldHasher`]s whose fixed seed
+    /// is part of the type.
+    ///
+    /// `ConstState<SEED>` hashes exactly like `FixedState::with_seed(SEED)`,
+    /// but doesn't store the seed. Combined with [`seed_from_label`] this gives
+    /// each logical table a distinct compile-time hash family:
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use std::hash::BuildHasher;
+    /// use foldhash::fast::{ConstState, FixedState};
+    /// use foldhash::seed_from_label;
+    ///
+    /// type UsersState = ConstState<{ seed_from_label("users") }>;
+    ///
+    /// let mut users: HashMap<u32, &str, UsersState> = HashMap::default();
+    /// users.insert(1, "alice");
+    /// assert_eq!(std::mem::size_of::<UsersState>(), 0);
+    /// assert_eq!(
+    ///     UsersState::default().hash_one(1),
+    ///     FixedState::with_seed(seed_from_label("users")).hash_one(1),
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Default, Debug)]
+    pub struct ConstState<const SEED: u64>;
+
+    impl<const SEED: u64> BuildHasher for ConstState<SEED> {
+        type Hasher = FoldHasher;
+
+        #[inline(always)]
+        fn build_hasher(&self) -> FoldHasher {
+            FixedState::with_seed(SEED).build_hasher_const()
         }
     }
 }
@@ -136,6 +1613,27 @@ pub mod quality {
         inner: fast::RandomState,
     }
 
+    impl RandomState {
+        /// Returns the [`fast::RandomState`] with the same seeds as this state.
+        ///
+        /// The two produce different hashes for the same input, but they are
+        /// not independent: the quality hash is a fixed function of the fast
+        /// hash, see [`fast::FoldHasher::into_quality`](crate::fast::FoldHasher::into_quality).
+        ///
+        /// ```rust
+        /// use std::hash::BuildHasher;
+        /// use foldhash::quality::RandomState;
+        ///
+        /// let quality = RandomState::default();
+        /// let fast = quality.fast();
+        /// assert_ne!(quality.hash_one(42), fast.hash_one(42));
+        /// ```
+        #[inline(always)]
+        pub fn fast(&self) -> fast::RandomState {
+            self.inner
+        }
+    }
+
     impl BuildHasher for RandomState {
         type Hasher = FoldHasher;
 
@@ -167,6 +1665,15 @@ pub mod quality {
                 inner: fast::FixedState::with_seed(folded_multiply(seed, ARBITRARY8)),
             }
         }
+
+        /// Creates a [`FoldHasher`] in a `const` context, identical to the one
+        /// returned by [`BuildHasher::build_hasher`].
+        #[inline(always)]
+        pub const fn build_hasher_const(&self) -> FoldHasher {
+            FoldHasher {
+                inner: self.inner.build_hasher_const(),
+            }
+        }
     }
 
     impl BuildHasher for FixedState {
@@ -174,13 +1681,28 @@ pub mod quality {
 
         #[inline(always)]
         fn build_hasher(&self) -> FoldHasher {
-            FoldHasher {
-                inner: self.inner.build_hasher(),
-            }
+            self.build_hasher_const()
+        }
+    }
+
+    impl BuildHasher for &FixedState {
+        type Hasher = FoldHasher;
+
+        #[inline(always)]
+        fn build_hasher(&self) -> FoldHasher {
+            self.build_hasher_const()
         }
     }
 }
 
+/// Bits forced on in every global seed.
+///
+/// Zeroes form a weak-point for the multiply-mix, and zeroes tend to be a
+/// common input. So we want our global seeds that are XOR'ed with the input to
+/// always be non-zero. To also ensure there is always a good spread of bits, we
+/// give up 3 bits of entropy and simply force some bits on.
+pub(crate) const FORCED_ONES: u64 = (1 << 63) | (1 << 31) | 1;
+
 #[cfg(target_has_atomic = "8")]
 mod global {
     use super::*;
@@ -196,7 +1718,7 @@ mod global {
         // no dependencies.
         let mut seed = 0;
         let stack_ptr = &seed as *const _;
-        let func_ptr = generate_global_seed;
+        let func_ptr = generate_global_seed as *const ();
         let static_ptr = &GLOBAL_SEED_STORAGE as *const _;
         seed = mix(seed, stack_ptr as usize as u64);
         seed = mix(seed, func_ptr as usize as u64);
@@ -211,13 +1733,33 @@ mod global {
                 all(target_family = "wasm", target_os = "unknown"),
                 target_os = "zkvm"
             )))]
-            if let Ok(duration) = std::time::UNIX_EPOCH.elapsed() {
+            let clock = std::time::UNIX_EPOCH.elapsed().ok();
+            #[cfg(any(
+                miri,
+                all(target_family = "wasm", target_os = "unknown"),
+                target_os = "zkvm"
+            ))]
+            let clock: Option<std::time::Duration> = None;
+
+            if let Some(duration) = clock {
                 seed = mix(seed, duration.subsec_nanos() as u64);
                 seed = mix(seed, duration.as_secs());
             }
 
             let box_ptr = &*Box::new(0u8) as *const _;
             seed = mix(seed, box_ptr as usize as u64);
+
+            // With the harden feature we refuse to continue if the clock didn't
+            // contribute. This only checks the clock: the remaining sources are
+            // all addresses, which are never zero, and whether they are
+            // near-constant because address space layout randomization is
+            // disabled can't be told from a single run.
+            #[cfg(feature = "harden")]
+            assert!(
+                matches!(clock, Some(duration) if !duration.is_zero()),
+                "foldhash: no time source available to seed the global hasher state, \
+                 refusing to run with a potentially near-constant seed (\"harden\" feature)"
+            );
         }
 
         let seed_a = mix(seed, 0);
@@ -225,11 +1767,6 @@ mod global {
         let seed_c = mix(mix(mix(seed_b, 0), 0), 0);
         let seed_d = mix(mix(mix(seed_c, 0), 0), 0);
 
-        // Zeroes form a weak-point for the multiply-mix, and zeroes tend to be
-        // a common input. So we want our global seeds that are XOR'ed with the
-        // input to always be non-zero. To also ensure there is always a good spread
-        // of bits, we give up 3 bits of entropy and simply force some bits on.
-        const FORCED_ONES: u64 = (1 << 63) | (1 << 31) | 1;
         [
             seed_a | FORCED_ONES,
             seed_b | FORCED_ONES,
@@ -287,8 +1824,12 @@ mod global {
         #[inline(never)]
         fn init_slow() {
             // Generate seed outside of critical section.
-            let seed = generate_global_seed();
+            Self::init_with(generate_global_seed());
+        }
 
+        /// Initializes the global seed to `seed` if it wasn't initialized yet.
+        /// Returns whether the global seed now equals `seed`.
+        pub fn init_with(seed: [u64; 4]) -> bool {
             loop {
                 match GLOBAL_SEED_STORAGE.state.compare_exchange_weak(
                     UNINIT,
@@ -300,10 +1841,11 @@ mod global {
                         // SAFETY: we just acquired an exclusive lock.
                         *GLOBAL_SEED_STORAGE.seed.get() = seed;
                         GLOBAL_SEED_STORAGE.state.store(INIT, Ordering::Release);
-                        return;
+                        return true;
                     },
 
-                    Err(INIT) => return,
+                    // SAFETY: we are in the INIT state, see get.
+                    Err(INIT) => return unsafe { *GLOBAL_SEED_STORAGE.seed.get() } == seed,
 
                     // Yes, it's a spin loop. We need to support no_std (so no easy
                     // access to proper locks), this is a one-time-per-program
@@ -334,6 +1876,11 @@ mod global {
             Self {}
         }
 
+        #[inline(always)]
+        pub fn init_with(seed: [u64; 4]) -> bool {
+            seed == super::FIXED_GLOBAL_SEED
+        }
+
         #[inline(always)]
         pub fn get(self) -> &'static [u64; 4] {
             &super::FIXED_GLOBAL_SEED
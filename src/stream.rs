@@ -0,0 +1,153 @@
+use core::hash::Hasher;
+
+use crate::fast::FoldHasher;
+use crate::{hash_bytes_medium, hash_chunk_long, MEDIUM_MAX};
+
+const BUF_LEN: usize = MEDIUM_MAX + 1;
+
+/// A source of bytes arriving in chunks, e.g. from a peripheral, to be hashed
+/// with [`RandomState::hash_source`](crate::fast::RandomState::hash_source).
+///
+/// ```rust
+/// use std::hash::BuildHasher;
+/// use foldhash::ByteSource;
+/// use foldhash::fast::RandomState;
+///
+/// // Hands out the data in chunks of increasing size.
+/// struct Chunked<'a> {
+///     data: &'a [u8],
+///     chunk_len: usize,
+/// }
+///
+/// impl ByteSource for Chunked<'_> {
+///     fn next_chunk(&mut self) -> Option<&[u8]> {
+///         if self.data.is_empty() {
+///             return None;
+///         }
+///         let (chunk, rest) = self.data.split_at(self.chunk_len.min(self.data.len()));
+///         self.data = rest;
+///         self.chunk_len += 1;
+///         Some(chunk)
+///     }
+/// }
+///
+/// let s = RandomState::default();
+/// let data: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+/// let mut source = Chunked { data: &data, chunk_len: 1 };
+/// assert_eq!(s.hash_source(&mut source), s.hash_one(&data));
+/// ```
+pub trait ByteSource {
+    /// Returns the next chunk of bytes, or `None` when the source is exhausted.
+    fn next_chunk(&mut self) -> Option<&[u8]>;
+}
+
+/// Feeds bytes arriving in pieces into a [`FoldHasher`], with the same result
+/// as a single [`Hasher::write`] of all the bytes concatenated.
+///
+/// Up to `BUF_LEN` bytes are buffered, which is enough to decide which path a
+/// single write would take. Beyond that the long path is followed one 64-byte
+/// chunk at a time, remembering the last 16 processed bytes as the final pass
+/// over the remainder may overlap the last chunk.
+pub(crate) struct ByteStream<'a> {
+    hasher: &'a mut FoldHasher,
+    buf: [u8; BUF_LEN],
+    len: usize,
+    long: Option<LongState>,
+}
+
+struct LongState {
+    lanes: [u64; 4],
+    tail: [u8; 16],
+}
+
+impl<'a> ByteStream<'a> {
+    #[inline(always)]
+    pub(crate) fn new(hasher: &'a mut FoldHasher) -> Self {
+        Self {
+            hasher,
+            buf: [0; BUF_LEN],
+            len: 0,
+            long: None,
+        }
+    }
+
+    pub(crate) fn write(&mut self, mut bytes: &[u8]) {
+        if self.long.is_none() {
+            let take = (BUF_LEN - self.len).min(bytes.len());
+            self.buf[self.len..self.len + take].copy_from_slice(&bytes[..take]);
+            self.len += take;
+            bytes = &bytes[take..];
+            if bytes.is_empty() {
+                return;
+            }
+
+            // The buffer is full and more bytes follow, so a single write
+            // would take the long path. Absorb all but the last chunk, which
+            // might turn out to be the final one.
+            let h = &*self.hasher;
+            let mut long = LongState {
+                lanes: [h.accumulator, h.expand_seed, h.expand_seed2, h.expand_seed3],
+                tail: [0; 16],
+            };
+            for chunk in self.buf[..BUF_LEN - 64].chunks_exact(64) {
+                long.absorb(chunk, h.fold_seed);
+            }
+            self.buf.copy_within(BUF_LEN - 64.., 0);
+            self.len = 64;
+            self.long = Some(long);
+        }
+
+        let long = self.long.as_mut().unwrap();
+        while !bytes.is_empty() {
+            if self.len == 64 {
+                long.absorb(&self.buf[..64], self.hasher.fold_seed);
+                self.len = 0;
+            }
+            let take = (64 - self.len).min(bytes.len());
+            self.buf[self.len..self.len + take].copy_from_slice(&bytes[..take]);
+            self.len += take;
+            bytes = &bytes[take..];
+        }
+    }
+
+    pub(crate) fn finish(self) {
+        let Some(mut long) = self.long else {
+            self.hasher.write(&self.buf[..self.len]);
+            return;
+        };
+
+        let fold_seed = self.hasher.fold_seed;
+        if self.len == 64 {
+            long.absorb(&self.buf[..64], fold_seed);
+        }
+        let [s0, s1, s2, s3] = long.lanes;
+        let (s0, s1) = (s0 ^ s2, s1 ^ s3);
+        self.hasher.accumulator = if self.len == 64 {
+            s0 ^ s1
+        } else if self.len >= 16 {
+            hash_bytes_medium(&self.buf[..self.len], s0, s1, fold_seed)
+        } else {
+            let mut last = [0; 16];
+            last[..16 - self.len].copy_from_slice(&long.tail[self.len..]);
+            last[16 - self.len..].copy_from_slice(&self.buf[..self.len]);
+            hash_bytes_medium(&last, s0, s1, fold_seed)
+        };
+    }
+}
+
+impl LongState {
+    #[inline(always)]
+    fn absorb(&mut self, chunk: &[u8], fold_seed: u64) {
+        let [s0, s1, s2, s3] = &mut self.lanes;
+        hash_chunk_long(chunk, [s0, s1, s2, s3], fold_seed);
+        self.tail.copy_from_slice(&chunk[48..64]);
+    }
+}
+
+impl core::fmt::Write for ByteStream<'_> {
+    #[inline(always)]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.write(s.as_bytes());
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! Helpers for testing code that uses hash maps.
+
+use crate::fast::SeedableRandomState;
+use crate::seed::FORCED_ONES;
+use crate::SeedSequence;
+
+/// Returns a [`SeedableRandomState`] with both a fresh per-hasher seed and a
+/// fresh global seed, to shake out code that depends on hash map iteration
+/// order.
+///
+/// Unlike [`RandomState`](crate::fast::RandomState), whose hashers all share
+/// the process-wide global seed, every call gets unrelated seeds, so maps
+/// built with different states are laid out independently even within one
+/// test run. When a test fails, its seeds can be logged with
+/// [`per_hasher_seed`](SeedableRandomState::per_hasher_seed) and
+/// [`global_seed`](SeedableRandomState::global_seed) and pinned again with
+/// [`SeedableRandomState::with_seed`] to reproduce the order.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use foldhash::testing::shuffled_state;
+///
+/// let mut map = HashMap::with_hasher(shuffled_state());
+/// map.extend([(1, "one"), (2, "two"), (3, "three")]);
+/// let mut values: Vec<_> = map.values().collect();
+/// values.sort(); // Don't rely on the iteration order.
+/// assert_eq!(values, [&"one", &"three", &"two"]);
+/// ```
+pub fn shuffled_state() -> SeedableRandomState {
+    let mut seeds = SeedSequence::new(crate::seed::fast::random_per_hasher_seed());
+    let mut next = || seeds.next().unwrap();
+    let per_hasher_seed = next();
+    let global_seed = [
+        next() | FORCED_ONES,
+        next() | FORCED_ONES,
+        next() | FORCED_ONES,
+        next() | FORCED_ONES,
+    ];
+    SeedableRandomState::with_random_seed(per_hasher_seed, global_seed)
+}
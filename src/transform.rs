@@ -0,0 +1,97 @@
+use core::hash::Hasher;
+
+use crate::fast::FoldHasher;
+
+/// A [`Hasher`] which streams all bytes through a user-provided transform
+/// before they reach a [`fast::FoldHasher`](FoldHasher).
+///
+/// The transform is called with every slice passed to [`Hasher::write`] along
+/// with a sink, and may call the sink any number of times with the transformed
+/// bytes, each of which is written to the inner hasher as-is. Integers written
+/// through the other `write_*` methods bypass the transform.
+///
+/// This allows e.g. case-folding or trimming keys while hashing, without
+/// allocating a preprocessed copy:
+///
+/// ```rust
+/// use std::hash::{BuildHasher, Hash, Hasher};
+/// use foldhash::fast::FixedState;
+/// use foldhash::TransformHasher;
+///
+/// fn trim(bytes: &[u8], sink: &mut dyn FnMut(&[u8])) {
+///     let start = bytes.iter().position(|b| !b.is_ascii_whitespace());
+///     let end = bytes.iter().rposition(|b| !b.is_ascii_whitespace());
+///     if let (Some(start), Some(end)) = (start, end) {
+///         sink(&bytes[start..=end]);
+///     }
+/// }
+///
+/// let state = FixedState::with_seed(42);
+/// let mut a = TransformHasher::new(state.build_hasher(), trim);
+/// let mut b = TransformHasher::new(state.build_hasher(), trim);
+/// "  hello ".hash(&mut a);
+/// "hello".hash(&mut b);
+/// assert_eq!(a.finish(), b.finish());
+/// ```
+#[derive(Clone)]
+pub struct TransformHasher<F> {
+    inner: FoldHasher,
+    transform: F,
+}
+
+impl<F: FnMut(&[u8], &mut dyn FnMut(&[u8]))> TransformHasher<F> {
+    /// Creates a [`TransformHasher`] feeding the transformed bytes into `inner`.
+    #[inline(always)]
+    pub fn new(inner: FoldHasher, transform: F) -> Self {
+        Self { inner, transform }
+    }
+
+    /// Returns the inner hasher.
+    #[inline(always)]
+    pub fn into_inner(self) -> FoldHasher {
+        self.inner
+    }
+}
+
+impl<F: FnMut(&[u8], &mut dyn FnMut(&[u8]))> Hasher for TransformHasher<F> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        let inner = &mut self.inner;
+        (self.transform)(bytes, &mut |out| inner.write(out));
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.inner.write_u8(i);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.inner.write_u16(i);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.inner.write_u32(i);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.inner.write_u64(i);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.inner.write_u128(i);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.inner.write_usize(i);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.inner.finish()
+    }
+}
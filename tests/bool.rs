@@ -0,0 +1,61 @@
+use std::hash::{BuildHasher, Hash};
+
+use foldhash::{fast, quality, SeedSequence};
+
+const NUM_SEEDS: usize = 10_000;
+
+/// Returns for each output bit how often it differs between the hashes of `a`
+/// and `b`, over many seeds.
+fn flip_rates<S: BuildHasher, T: Hash>(mut state: impl FnMut(u64) -> S, a: T, b: T) -> [f64; 64] {
+    // Consecutive seeds would only vary the low bits of the hasher state, so
+    // draw well-separated ones instead.
+    let mut flips = [0usize; 64];
+    for seed in SeedSequence::new(0).take(NUM_SEEDS) {
+        let s = state(seed);
+        let diff = s.hash_one(&a) ^ s.hash_one(&b);
+        for (bit, count) in flips.iter_mut().enumerate() {
+            *count += (diff >> bit) as usize & 1;
+        }
+    }
+    flips.map(|c| c as f64 / NUM_SEEDS as f64)
+}
+
+/// Every output bit must flip in about half of the cases. The standard
+/// deviation of each rate is 0.005.
+fn assert_full_avalanche(rates: [f64; 64]) {
+    for (bit, rate) in rates.iter().enumerate() {
+        assert!(
+            (0.45..0.55).contains(rate),
+            "bit {bit} flips with rate {rate}"
+        );
+    }
+}
+
+/// The hashes must differ in about half of the bits on average. The fast
+/// hasher's single folded multiply leaves the difference between two inputs
+/// that differ in one bit correlated with the global seed, so individual
+/// output bits can be biased.
+fn assert_half_differ(rates: [f64; 64]) {
+    let mean_distance: f64 = rates.iter().sum();
+    assert!(
+        (28.0..36.0).contains(&mean_distance),
+        "mean distance {mean_distance}"
+    );
+}
+
+#[test]
+fn lone_bool() {
+    assert_full_avalanche(flip_rates(quality::FixedState::with_seed, true, false));
+    assert_half_differ(flip_rates(fast::FixedState::with_seed, true, false));
+}
+
+#[test]
+fn bool_in_tuple() {
+    let (t, f) = ((1234u64, true), (1234u64, false));
+    assert_full_avalanche(flip_rates(quality::FixedState::with_seed, t, f));
+    assert_half_differ(flip_rates(fast::FixedState::with_seed, t, f));
+
+    let (t, f) = ((true, 0u8, "GET"), (false, 0u8, "GET"));
+    assert_full_avalanche(flip_rates(quality::FixedState::with_seed, t, f));
+    assert_half_differ(flip_rates(fast::FixedState::with_seed, t, f));
+}
@@ -0,0 +1,59 @@
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use foldhash::{fast, quality};
+
+/// Clones a hasher after a common prefix and checks each clone against a
+/// hasher fed prefix and suffix from scratch.
+fn check_checkpoint<S: BuildHasher>(state: &S)
+where
+    S::Hasher: Clone,
+{
+    let prefixes: [&[u8]; 4] = [b"", b"let x", &[7; 16], &[9; 1000]];
+    let suffixes: [&[u8]; 4] = [b"", b" = 1;", b" = \"x\";", &[3; 300]];
+    for prefix in prefixes {
+        let mut checkpoint = state.build_hasher();
+        checkpoint.write(prefix);
+        checkpoint.write_u32(42);
+        for suffix in suffixes {
+            let mut speculative = checkpoint.clone();
+            speculative.write(suffix);
+            speculative.write_u64(suffix.len() as u64);
+
+            let mut fresh = state.build_hasher();
+            fresh.write(prefix);
+            fresh.write_u32(42);
+            fresh.write(suffix);
+            fresh.write_u64(suffix.len() as u64);
+            assert_eq!(speculative.finish(), fresh.finish());
+        }
+
+        // Cloning must not disturb the original.
+        let mut fresh = state.build_hasher();
+        fresh.write(prefix);
+        fresh.write_u32(42);
+        assert_eq!(checkpoint.finish(), fresh.finish());
+    }
+}
+
+#[test]
+fn cloned_hashers_continue_independently() {
+    check_checkpoint(&fast::FixedState::with_seed(42));
+    check_checkpoint(&fast::RandomState::default());
+    check_checkpoint(&fast::CompactState::default());
+    check_checkpoint(&quality::FixedState::with_seed(42));
+    check_checkpoint(&quality::RandomState::default());
+}
+
+#[test]
+fn hashers_are_copy() {
+    fn assert_copy<T: Copy>(_: T) {}
+    assert_copy(fast::RandomState::default().build_hasher());
+    assert_copy(fast::CompactState::default().build_hasher());
+    assert_copy(quality::RandomState::default().build_hasher());
+
+    let mut a = fast::FixedState::with_seed(1).build_hasher();
+    "prefix".hash(&mut a);
+    let mut b = a;
+    "suffix".hash(&mut b);
+    assert_ne!(a.finish(), b.finish());
+}
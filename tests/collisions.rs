@@ -0,0 +1,42 @@
+#![cfg(feature = "diagnostics")]
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+
+use foldhash::CollisionTrackingState;
+
+// Hashes every key to the same value.
+#[derive(Clone, Default)]
+struct Constant;
+
+impl Hasher for Constant {
+    fn write(&mut self, _bytes: &[u8]) {}
+
+    fn finish(&self) -> u64 {
+        0
+    }
+}
+
+impl BuildHasher for Constant {
+    type Hasher = Constant;
+
+    fn build_hasher(&self) -> Constant {
+        Constant
+    }
+}
+
+#[test]
+fn counts_distinct_keys_not_lookups() {
+    let state = CollisionTrackingState::new(Constant);
+    let mut map = HashMap::with_hasher(state.clone());
+    for key in 0..100u32 {
+        map.insert(key, key);
+    }
+    for _ in 0..10 {
+        for key in 0..100u32 {
+            assert_eq!(map[&key], key);
+        }
+    }
+    assert_eq!(state.distinct_keys(), 100);
+    assert_eq!(state.colliding_keys(), 99);
+}
@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use foldhash::{fast, quality};
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, Debug)]
+enum Fieldless {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+enum Explicit {
+    Low = 1,
+    Mid = 100,
+    High = 255,
+}
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(i64)]
+enum Signed {
+    Negative = -1,
+    Zero = 0,
+    Max = i64::MAX,
+}
+
+#[derive(Hash, Clone, PartialEq, Eq, Debug)]
+enum Nested {
+    Unit,
+    Tuple(Fieldless, u8),
+    Struct { inner: Option<Explicit>, tag: bool },
+    Recursive(Box<Nested>),
+}
+
+#[derive(Hash, Clone, PartialEq, Eq, Debug)]
+struct Generic<T, U> {
+    first: T,
+    second: U,
+}
+
+#[derive(Hash, Clone, PartialEq, Eq, Debug)]
+struct Newtype(u32);
+
+#[derive(Hash, Clone, PartialEq, Eq, Debug)]
+struct Empty;
+
+/// Hashes every value twice and asserts the hashes are stable and distinct.
+fn check_distinct<S: BuildHasher, T: Hash>(state: &S, values: &[T]) -> Vec<u64> {
+    let hashes: Vec<u64> = values.iter().map(|v| state.hash_one(v)).collect();
+    for (v, h) in values.iter().zip(&hashes) {
+        assert_eq!(state.hash_one(v), *h);
+    }
+    let unique: HashSet<_> = hashes.iter().collect();
+    assert_eq!(unique.len(), values.len(), "colliding derived values");
+    hashes
+}
+
+fn nested_values() -> Vec<Nested> {
+    let mut values = vec![Nested::Unit];
+    for f in [Fieldless::A, Fieldless::B, Fieldless::C, Fieldless::D] {
+        for x in [0, 1, 255] {
+            values.push(Nested::Tuple(f, x));
+        }
+    }
+    for inner in [
+        None,
+        Some(Explicit::Low),
+        Some(Explicit::Mid),
+        Some(Explicit::High),
+    ] {
+        for tag in [false, true] {
+            values.push(Nested::Struct { inner, tag });
+        }
+    }
+    let shallow = values.clone();
+    values.extend(shallow.into_iter().map(|n| Nested::Recursive(Box::new(n))));
+    values.push(Nested::Recursive(Box::new(Nested::Recursive(Box::new(
+        Nested::Unit,
+    )))));
+    values
+}
+
+fn check_derived<S: BuildHasher>(state: &S) {
+    let fieldless = check_distinct(
+        state,
+        &[Fieldless::A, Fieldless::B, Fieldless::C, Fieldless::D],
+    );
+    check_distinct(state, &[Explicit::Low, Explicit::Mid, Explicit::High]);
+    check_distinct(state, &[Signed::Negative, Signed::Zero, Signed::Max]);
+    check_distinct(state, &nested_values());
+
+    let generics: Vec<Generic<u8, Option<u16>>> = (0..=255u8)
+        .flat_map(|first| {
+            [None, Some(first as u16), Some(first as u16 + 256)]
+                .map(|second| Generic { first, second })
+        })
+        .collect();
+    check_distinct(state, &generics);
+    check_distinct(
+        state,
+        &[
+            Generic {
+                first: "ab",
+                second: "c",
+            },
+            Generic {
+                first: "a",
+                second: "bc",
+            },
+            Generic {
+                first: "",
+                second: "abc",
+            },
+        ],
+    );
+    check_distinct(state, &(0..1000).map(Newtype).collect::<Vec<_>>());
+
+    // Small discriminants must still affect the whole hash, not just a few
+    // low bits.
+    for i in 0..fieldless.len() {
+        for j in 0..i {
+            let distance = (fieldless[i] ^ fieldless[j]).count_ones();
+            assert!(
+                distance >= 12,
+                "variants {i} and {j} differ in {distance} bits"
+            );
+        }
+    }
+
+    // A unit struct writes nothing at all.
+    assert_eq!(state.hash_one(Empty), state.hash_one(()));
+}
+
+#[test]
+fn derived_hashes() {
+    check_derived(&fast::FixedState::with_seed(42));
+    check_derived(&quality::FixedState::with_seed(42));
+    for seed in foldhash::SeedSequence::new(7).take(20) {
+        check_derived(&fast::FixedState::with_seed(seed));
+        check_derived(&quality::FixedState::with_seed(seed));
+    }
+}
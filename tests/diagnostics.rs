@@ -0,0 +1,39 @@
+#![cfg(all(feature = "diagnostics", debug_assertions))]
+
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use foldhash::fast::FixedState;
+
+#[test]
+fn byte_loop_only_warns() {
+    let mut hasher = FixedState::with_seed(42).build_hasher();
+    for b in 0..1000u32 {
+        hasher.write_i8(b as i8);
+    }
+    let _ = hasher.finish();
+}
+
+#[test]
+fn std_byte_keys_are_fine() {
+    // The std Hash impls of these write one byte per element.
+    let mut pairs = foldhash::HashMap::default();
+    pairs.insert(vec![(1u8, 2u8); 200], 0);
+    assert_eq!(pairs.get(&vec![(1u8, 2u8); 200]), Some(&0));
+
+    let mut bools = foldhash::HashMap::default();
+    bools.insert(vec![true; 1000], 0);
+    assert_eq!(bools.get(&vec![true; 1000]), Some(&0));
+}
+
+#[test]
+fn interleaved_byte_writes_are_fine() {
+    let mut hasher = FixedState::with_seed(42).build_hasher();
+    for b in 0..1000u32 {
+        hasher.write_u8(b as u8);
+        if b % 100 == 0 {
+            hasher.write_u32(b);
+        }
+    }
+    [7u8; 1000].hash(&mut hasher);
+    let _ = hasher.finish();
+}
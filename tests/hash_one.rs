@@ -0,0 +1,221 @@
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::time::Duration;
+
+use foldhash::fast::{hash_one_bytes, RandomState};
+use foldhash::{fast, quality};
+
+/// The unspecialized way a map hashes a value.
+#[allow(clippy::manual_hash_one)]
+fn generic_hash<S: BuildHasher, T: Hash + ?Sized>(state: &S, value: &T) -> u64 {
+    let mut hasher = state.build_hasher();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn check_hash_one<S: BuildHasher>(state: &S) {
+    fn check<S: BuildHasher, T: Hash + ?Sized>(state: &S, value: &T) {
+        assert_eq!(state.hash_one(value), generic_hash(state, value));
+    }
+
+    for i in [0u64, 1, 42, 1 << 32, u64::MAX] {
+        check(state, &(i as u8));
+        check(state, &(i as u16));
+        check(state, &(i as u32));
+        check(state, &i);
+        check(state, &(i as u128));
+        check(state, &(i as usize));
+        check(state, &(i as i64));
+        check(state, &(i as u32, i as u8));
+        check(state, &(i, i, i));
+    }
+    check(state, &true);
+    check(state, &'x');
+    check(state, &());
+    for len in [0, 1, 3, 8, 16, 17, 100, 255, 256, 1000] {
+        let s = "ab".repeat(len);
+        check(state, &s);
+        check(state, s.as_str());
+        check(state, s.as_bytes());
+        check(state, &(s.as_str(), len));
+        check(state, &vec![len as u32; len]);
+        check(state, &vec![s.as_str(); len % 7]);
+    }
+}
+
+#[test]
+fn hash_one_matches_generic_path() {
+    check_hash_one(&fast::RandomState::default());
+    check_hash_one(&fast::FixedState::with_seed(42));
+    check_hash_one(&fast::SeedableRandomState::default());
+    check_hash_one(&quality::RandomState::default());
+    check_hash_one(&quality::FixedState::with_seed(42));
+}
+
+#[test]
+fn specialized_helpers_match_generic_path() {
+    let s = RandomState::default();
+    for i in [0u64, 1, 42, 1 << 32, u64::MAX] {
+        assert_eq!(s.hash_primitive(i), generic_hash(&s, &i));
+        assert_eq!(s.hash_primitive(i as u16), generic_hash(&s, &(i as u16)));
+        assert_eq!(s.hash_primitive(i as i128), generic_hash(&s, &(i as i128)));
+        assert_eq!(s.hash_nonzero(&i).get(), generic_hash(&s, &i));
+        let d = Duration::new(i, i as u32 % 1_000_000_000);
+        assert_eq!(s.hash_duration(d), generic_hash(&s, &d));
+    }
+    for len in [0, 1, 3, 8, 16, 17, 100, 255, 256, 1000] {
+        let text = "ab".repeat(len);
+        let bytes = text.as_bytes();
+        assert_eq!(hash_one_bytes(&s, bytes), generic_hash(&s, bytes));
+        assert_eq!(
+            s.hash_str_with_len(&text, text.len()),
+            generic_hash(&s, text.as_str())
+        );
+        assert_eq!(
+            s.hash_range(bytes, 1.min(len)..bytes.len()),
+            generic_hash(&s, &bytes[1.min(len)..])
+        );
+        assert_eq!(
+            s.hash_fmt(format_args!("{text}")),
+            generic_hash(&s, text.as_str())
+        );
+        assert_eq!(s.hash_concat(&[bytes, b""]), generic_hash(&s, bytes));
+        for chunk in [1, 7, 16, 64, 1000] {
+            assert_eq!(s.hash_bytes_chunked(bytes, chunk), generic_hash(&s, bytes));
+        }
+    }
+    assert_eq!(s.hash_array(&[7u8; 16]), generic_hash(&s, &[7u8; 16]));
+}
+
+#[test]
+fn hash_one_checked_flags_degenerate_keys() {
+    let s = RandomState::default();
+    for key in [1u64, 42, 1000, u64::MAX - 1] {
+        assert_eq!(s.hash_one_checked(key), (s.hash_one(key), true));
+    }
+    assert!(s.hash_one_checked("hello").1);
+    assert!(s.hash_one_checked(vec![1u32, 2, 3]).1);
+
+    // The check only runs in debug builds.
+    let degenerate = [
+        s.hash_one_checked(0u64).1,
+        s.hash_one_checked(u64::MAX).1,
+        s.hash_one_checked(7u8).1,
+        s.hash_one_checked(()).1,
+        s.hash_one_checked([0u8; 32]).1,
+        s.hash_one_checked(vec![0u64; 4]).1,
+    ];
+    assert!(degenerate.iter().all(|&ok| ok != cfg!(debug_assertions)));
+}
+
+#[test]
+fn hash_str_encoding_is_stable() {
+    // The encoding of hash_str is fixed as the bytes followed by 0xff, no
+    // matter how the toolchain's Hash for str is implemented.
+    let s = RandomState::default();
+    for len in [0, 1, 7, 8, 16, 17, 100, 1000] {
+        let text = "xy".repeat(len);
+        let mut hasher = s.build_hasher();
+        hasher.write(text.as_bytes());
+        hasher.write_u8(0xff);
+        assert_eq!(s.hash_str(&text), hasher.finish());
+
+        // Still consistent with String keys on this toolchain.
+        assert_eq!(s.hash_str(&text), generic_hash(&s, &text));
+        assert_eq!(s.hash_str(&text), generic_hash(&s, text.as_str()));
+    }
+}
+
+#[test]
+fn hash_trimmed_ignores_trailing_zeros() {
+    let s = RandomState::default();
+    for field in [&b""[..], b"x", b"abc", b"\0abc", b"a\0b\0c"] {
+        let expected = generic_hash(&s, field);
+        for padding in [0, 1, 5, 64, 300] {
+            let mut padded = field.to_vec();
+            padded.resize(field.len() + padding, 0);
+            assert_eq!(s.hash_trimmed(&padded), expected);
+        }
+    }
+    for len in [0, 1, 8, 17, 1000] {
+        assert_eq!(s.hash_trimmed(&vec![0; len]), generic_hash(&s, &b""[..]));
+    }
+}
+
+#[test]
+fn hash_entries_ignores_order() {
+    let s = RandomState::default();
+    let entries: Vec<(String, u32)> = (0..20).map(|i| (format!("key{i}"), i * 7)).collect();
+    let expected = s.hash_entries(&entries);
+
+    // Rotations and reversals of the entries hash the same.
+    for shift in 0..entries.len() {
+        let mut shuffled = entries.clone();
+        shuffled.rotate_left(shift);
+        assert_eq!(s.hash_entries(&shuffled), expected);
+        shuffled.reverse();
+        assert_eq!(s.hash_entries(&shuffled), expected);
+    }
+
+    // Entries are a multiset: counts and pairings matter.
+    let mut duplicated = entries.clone();
+    duplicated.push(entries[0].clone());
+    assert_ne!(s.hash_entries(&duplicated), expected);
+    assert_ne!(s.hash_entries(&entries[1..]), expected);
+    let mut swapped = entries.clone();
+    swapped[0].1 = entries[1].1;
+    swapped[1].1 = entries[0].1;
+    assert_ne!(s.hash_entries(&swapped), expected);
+    assert_ne!(
+        s.hash_entries(&[("a", 1), ("a", 1)]),
+        s.hash_entries(&[("a", 1)])
+    );
+}
+
+#[test]
+fn hash_named_separates_type_names() {
+    #[derive(Hash)]
+    struct Meters(u32);
+    #[derive(Hash)]
+    struct Feet(u32);
+
+    let s = RandomState::default();
+    let meters = s.hash_named(std::any::type_name::<Meters>(), Meters(3));
+    let feet = s.hash_named(std::any::type_name::<Feet>(), Feet(3));
+    assert_ne!(meters, feet);
+    assert_eq!(
+        meters,
+        s.hash_named(std::any::type_name::<Meters>(), Meters(3))
+    );
+
+    // The name boundary is delimited, so name and value can't blend.
+    assert_ne!(s.hash_named("ab", "c"), s.hash_named("a", "bc"));
+    assert_ne!(s.hash_named("v1", 7u32), s.hash_named("v2", 7u32));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn family_members_are_decorrelated() {
+    const NUM_KEYS: usize = 10_000;
+    let family = RandomState::default().family(8);
+    let hashes: Vec<Vec<u64>> = family
+        .iter()
+        .map(|f| (0..NUM_KEYS).map(|i| f.hash_one(format!("w{i}"))).collect())
+        .collect();
+
+    // Every output bit of any two members must agree on about half of the
+    // keys. The standard deviation of each rate is 0.005.
+    for i in 0..family.len() {
+        for j in i + 1..family.len() {
+            for bit in 0..64 {
+                let flips = (0..NUM_KEYS)
+                    .filter(|&k| (hashes[i][k] ^ hashes[j][k]) >> bit & 1 == 1)
+                    .count();
+                let rate = flips as f64 / NUM_KEYS as f64;
+                assert!(
+                    (0.45..0.55).contains(&rate),
+                    "members {i} and {j} bit {bit} flip rate {rate}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use foldhash::{fast, quality};
+
+fn assert_distinct<T: Hash, S: BuildHasher>(state: &S, values: impl IntoIterator<Item = T>) {
+    let mut seen = HashSet::new();
+    for (i, v) in values.into_iter().enumerate() {
+        assert!(seen.insert(state.hash_one(v)), "collision at value {i}");
+    }
+}
+
+fn check_state<S: BuildHasher>(state: S) {
+    // Only the length prefix distinguishes these.
+    assert_distinct(&state, (0..1000).map(|n| vec![(); n]));
+    assert_distinct(&state, (0..1000).map(|n| vec![0u8; n]));
+    assert_distinct(&state, (0..1000).map(|n| vec![0u64; n]));
+
+    // Slices hash their length through write_usize before their contents.
+    for n in [0, 1, 7, 16, 17, 255, 256, 1000] {
+        let data = vec![0xab; n];
+        let mut manual = state.build_hasher();
+        manual.write_usize(n);
+        manual.write(&data);
+        assert_eq!(state.hash_one(&data), manual.finish());
+    }
+}
+
+#[test]
+fn different_lengths_hash_distinctly() {
+    check_state(fast::FixedState::with_seed(42));
+    check_state(fast::RandomState::default());
+    check_state(quality::FixedState::with_seed(42));
+    check_state(quality::RandomState::default());
+}
+
+#[test]
+fn map_lookups_by_vec() {
+    let mut map = HashMap::with_hasher(fast::FixedState::with_seed(42));
+    for n in 0..300 {
+        map.insert(vec![0u8; n], n);
+    }
+    for n in 1..300 {
+        map.insert(vec![1u8; n], n + 1000);
+    }
+    assert_eq!(map.len(), 599);
+    for n in 0..300 {
+        assert_eq!(map.get(&vec![0u8; n]), Some(&n));
+    }
+    for n in 1..300 {
+        // Borrowed slices must find the Vec keys.
+        assert_eq!(map.get(&vec![1u8; n][..]), Some(&(n + 1000)));
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use foldhash::{fast, quality, ByAddress};
+
+fn check_borrowed_state<S>(state: &S)
+where
+    S: BuildHasher,
+    for<'a> &'a S: BuildHasher,
+{
+    let mut map = HashMap::with_hasher(state);
+    for i in 0..1000u32 {
+        map.insert(i, i * 2);
+    }
+    for i in 0..1000u32 {
+        assert_eq!(map.get(&i), Some(&(i * 2)));
+    }
+    assert_eq!((&state).hash_one("key"), state.hash_one("key"));
+}
+
+#[test]
+fn borrowed_fixed_state() {
+    check_borrowed_state(&fast::FixedState::with_seed(42));
+    check_borrowed_state(&quality::FixedState::with_seed(42));
+}
+
+#[test]
+fn by_address_spreads_sequential_allocations() {
+    let boxes: Vec<Box<u64>> = (0..10_000).map(Box::new).collect();
+    let state = fast::RandomState::default();
+
+    // The addresses themselves share their low bits due to alignment, so
+    // using them directly as bucket indices would leave most buckets empty.
+    let mut buckets = vec![0u32; 1024];
+    for b in &boxes {
+        let hash = state.hash_one(ByAddress(&**b));
+        buckets[hash as usize % 1024] += 1;
+    }
+    let expected = boxes.len() as f64 / buckets.len() as f64;
+    let max = *buckets.iter().max().unwrap() as f64;
+    let empty = buckets.iter().filter(|&&c| c == 0).count();
+    assert!(max < 4.0 * expected, "fullest bucket has {max} keys");
+    assert!(empty < 5, "{empty} empty buckets");
+
+    let mut map = HashMap::with_hasher(state);
+    for (i, b) in boxes.iter().enumerate() {
+        map.insert(ByAddress(&**b), i);
+    }
+    for (i, b) in boxes.iter().enumerate() {
+        assert_eq!(map[&ByAddress(&**b)], i);
+    }
+}
+
+fn check_survives_rehash<S: BuildHasher + Clone>(state: S) {
+    let keys: Vec<String> = (0..20_000).map(|i| format!("key-{i}")).collect();
+    let hashes: Vec<u64> = keys.iter().map(|k| state.hash_one(k)).collect();
+
+    let mut map = HashMap::with_hasher(state.clone());
+    let mut resizes = 0;
+    for (i, key) in keys.iter().enumerate() {
+        let capacity = map.capacity();
+        map.insert(key.clone(), i);
+        resizes += (map.capacity() != capacity) as usize;
+    }
+    assert!(resizes >= 5, "only {resizes} resizes");
+
+    // Removing most keys and shrinking rehashes everything once more.
+    map.retain(|_, i| *i % 16 == 0);
+    map.shrink_to_fit();
+    for (i, key) in keys.iter().enumerate() {
+        assert_eq!(map.get(key), (i % 16 == 0).then_some(&i));
+    }
+
+    // The state must keep producing identical hashes across calls.
+    for (key, hash) in keys.iter().zip(&hashes) {
+        assert_eq!(state.hash_one(key), *hash);
+    }
+}
+
+#[test]
+fn maps_survive_rehash() {
+    check_survives_rehash(fast::RandomState::default());
+    check_survives_rehash(fast::FixedState::with_seed(42));
+    check_survives_rehash(fast::SeedableRandomState::default());
+    check_survives_rehash(foldhash::GlobalDefault);
+    check_survives_rehash(quality::RandomState::default());
+    check_survives_rehash(quality::FixedState::with_seed(42));
+}
+
+#[test]
+fn random_states_differ_from_the_same_stack_frame() {
+    // Every state below is created at the same stack address, so the
+    // per-hasher seeds must differ through the thread-local chaining alone.
+    fn hashes() -> Vec<u64> {
+        (0..100_000)
+            .map(|_| fast::RandomState::default().hash_one(0u64))
+            .collect()
+    }
+
+    let mut all = hashes();
+    let threads: Vec<_> = (0..4).map(|_| std::thread::spawn(hashes)).collect();
+    for t in threads {
+        all.extend(t.join().unwrap());
+    }
+    let unique: std::collections::HashSet<_> = all.iter().collect();
+    assert_eq!(unique.len(), all.len());
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use foldhash::{fast, quality};
+
+/// All `Vec<Vec<u8>>` with at most `max_inner` inner vectors of at most
+/// `max_len` bytes each, drawn from a two-letter alphabet.
+fn nested_u8(max_inner: usize, max_len: usize) -> Vec<Vec<Vec<u8>>> {
+    let mut inners = vec![vec![]];
+    let mut frontier = vec![vec![]];
+    for _ in 0..max_len {
+        let mut next = Vec::new();
+        for v in &frontier {
+            for b in [b'a', b'b'] {
+                let mut w: Vec<u8> = v.clone();
+                w.push(b);
+                next.push(w);
+            }
+        }
+        inners.extend(next.iter().cloned());
+        frontier = next;
+    }
+
+    let mut out = vec![vec![]];
+    let mut frontier: Vec<Vec<Vec<u8>>> = vec![vec![]];
+    for _ in 0..max_inner {
+        let mut next = Vec::new();
+        for v in &frontier {
+            for inner in &inners {
+                let mut w = v.clone();
+                w.push(inner.clone());
+                next.push(w);
+            }
+        }
+        out.extend(next.iter().cloned());
+        frontier = next;
+    }
+    out
+}
+
+fn assert_distinct<T: Hash + std::fmt::Debug, S: BuildHasher>(state: &S, values: &[T]) {
+    let mut seen = HashSet::new();
+    for v in values {
+        assert!(seen.insert(state.hash_one(v)), "hash collision for {v:?}");
+    }
+}
+
+#[test]
+fn nested_vecs_are_distinct() {
+    let values = nested_u8(3, 3);
+    assert!(values.len() > 3000);
+    for seed in 0..4 {
+        assert_distinct(&fast::FixedState::with_seed(seed), &values);
+        assert_distinct(&quality::FixedState::with_seed(seed), &values);
+    }
+    assert_distinct(&fast::RandomState::default(), &values);
+}
+
+#[test]
+fn nesting_boundaries_are_distinct() {
+    let a: Vec<Vec<u8>> = vec![vec![1], vec![2]];
+    let b: Vec<Vec<u8>> = vec![vec![1, 2]];
+    let c: Vec<Vec<u8>> = vec![vec![], vec![1, 2]];
+    let d: Vec<Vec<u8>> = vec![vec![1, 2], vec![]];
+    let state = fast::FixedState::with_seed(42);
+    assert_distinct(&state, &[a, b, c, d]);
+
+    let e: Vec<Vec<Vec<u8>>> = vec![vec![vec![1]], vec![vec![2]]];
+    let f: Vec<Vec<Vec<u8>>> = vec![vec![vec![1], vec![2]]];
+    let g: Vec<Vec<Vec<u8>>> = vec![vec![vec![1, 2]]];
+    let h: Vec<Vec<Vec<u8>>> = vec![vec![], vec![vec![1, 2]]];
+    assert_distinct(&state, &[e, f, g, h]);
+
+    let strs: [Vec<String>; 4] = [
+        vec!["ab".into(), "c".into()],
+        vec!["a".into(), "bc".into()],
+        vec!["abc".into()],
+        vec!["abc".into(), "".into()],
+    ];
+    assert_distinct(&state, &strs);
+}
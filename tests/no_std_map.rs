@@ -0,0 +1,89 @@
+//! A fixed-capacity, stack-allocated map as used on embedded targets,
+//! written against `core` only.
+
+#![no_std]
+
+use core::hash::{BuildHasher, Hash};
+
+use foldhash::fast::{FixedState, RandomState};
+
+/// A minimal open-addressing map with linear probing and `N` slots.
+struct FixedMap<K, V, S, const N: usize> {
+    slots: [Option<(K, V)>; N],
+    state: S,
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, const N: usize> FixedMap<K, V, S, N> {
+    fn with_hasher(state: S) -> Self {
+        assert!(N.is_power_of_two());
+        Self {
+            slots: [const { None }; N],
+            state,
+        }
+    }
+
+    fn probe(&self, key: &K) -> impl Iterator<Item = usize> {
+        let start = self.state.hash_one(key) as usize;
+        (0..N).map(move |i| start.wrapping_add(i) & (N - 1))
+    }
+
+    /// Inserts a key-value pair, returning it back if the map is full.
+    fn insert(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        for i in self.probe(&key) {
+            match &mut self.slots[i] {
+                Some((k, v)) if *k == key => {
+                    *v = value;
+                    return Ok(());
+                }
+                Some(_) => {}
+                slot @ None => {
+                    *slot = Some((key, value));
+                    return Ok(());
+                }
+            }
+        }
+        Err((key, value))
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        for i in self.probe(key) {
+            match &self.slots[i] {
+                Some((k, v)) if k == key => return Some(v),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+        None
+    }
+}
+
+fn check_fixed_map<S: BuildHasher>(state: S) {
+    let mut map: FixedMap<u32, u32, S, 64> = FixedMap::with_hasher(state);
+    for key in 0..48 {
+        assert!(map.insert(key * 7, key).is_ok());
+    }
+    assert!(map.insert(7, 100).is_ok());
+    for key in 0..48 {
+        let expected = if key == 1 { 100 } else { key };
+        assert_eq!(map.get(&(key * 7)), Some(&expected));
+    }
+    assert_eq!(map.get(&1), None);
+
+    for key in 48..64 {
+        assert!(map.insert(key * 7, key).is_ok());
+    }
+    assert_eq!(map.insert(1, 1), Err((1, 1)));
+}
+
+#[test]
+fn fixed_state_in_stack_map() {
+    check_fixed_map(FixedState::with_seed(42));
+    check_fixed_map(FixedState::default());
+}
+
+#[test]
+fn random_state_in_stack_map() {
+    // Without the "std" feature this exercises the stack address and atomic
+    // counter seeding fallback.
+    check_fixed_map(RandomState::default());
+}
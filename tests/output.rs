@@ -0,0 +1,50 @@
+use std::hash::BuildHasher;
+
+use foldhash::fast::{FixedState, RandomState};
+use foldhash::shard;
+
+/// Asserts the counts are within 5 standard deviations of uniform.
+fn assert_uniform(counts: &[u64]) {
+    let total: u64 = counts.iter().sum();
+    let p = 1.0 / counts.len() as f64;
+    let expected = total as f64 * p;
+    let sigma = (total as f64 * p * (1.0 - p)).sqrt();
+    for (i, &count) in counts.iter().enumerate() {
+        let z = (count as f64 - expected) / sigma;
+        assert!(z.abs() < 5.0, "shard {i} got {count} of {total}, z = {z}");
+    }
+}
+
+#[test]
+fn shard_is_uniform() {
+    let state = FixedState::with_seed(42);
+    for shards in [2, 3, 7, 10, 64, 1000] {
+        let mut counts = vec![0u64; shards as usize];
+        for key in 0..1_000_000u64 {
+            counts[shard(state.hash_one(key), shards) as usize] += 1;
+        }
+        assert_uniform(&counts);
+    }
+}
+
+#[test]
+fn shard_is_in_range() {
+    for shards in [1, 2, 3, 1000, u32::MAX] {
+        for hash in [0, 1, 1 << 32, 1 << 63, u64::MAX - 1, u64::MAX] {
+            assert!(shard(hash, shards) < shards);
+        }
+        assert_eq!(shard(u64::MAX, shards), shards - 1);
+    }
+}
+
+#[test]
+fn hash_to_unit_f64_is_uniform() {
+    let state = RandomState::default();
+    let mut counts = vec![0u64; 100];
+    for key in 0..1_000_000u64 {
+        let x = state.hash_to_unit_f64(key);
+        assert!((0.0..1.0).contains(&x));
+        counts[(x * 100.0) as usize] += 1;
+    }
+    assert_uniform(&counts);
+}
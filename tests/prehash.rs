@@ -0,0 +1,71 @@
+use std::hash::BuildHasher;
+
+use foldhash::{fast, quality};
+use hashbrown::HashTable;
+
+/// Looks up `key` in a table of key-value pairs with a hash computed earlier
+/// by [`BuildHasher::hash_one`] on the same state.
+fn find_by_prehash<'a, K: Eq, V>(
+    table: &'a HashTable<(K, V)>,
+    hash: u64,
+    key: &K,
+) -> Option<&'a V> {
+    table.find(hash, |(k, _)| k == key).map(|(_, v)| v)
+}
+
+fn keys() -> Vec<String> {
+    (0..10_000).map(|i| format!("key-{i}")).collect()
+}
+
+#[test]
+fn hash_once_probe_many() {
+    let state = fast::RandomState::default();
+    let mut table = HashTable::new();
+    for (i, key) in keys().into_iter().enumerate() {
+        let hash = state.hash_one(&key);
+        table.insert_unique(hash, (key, i), |(k, _)| state.hash_one(k));
+    }
+
+    for (i, key) in keys().iter().enumerate() {
+        let hash = state.hash_one(key);
+        for _ in 0..3 {
+            assert_eq!(find_by_prehash(&table, hash, key), Some(&i));
+        }
+    }
+}
+
+fn assert_matches_map_hash<S: BuildHasher + Clone>(state: S) {
+    let mut map = hashbrown::HashMap::with_hasher(state.clone());
+    for (i, key) in keys().into_iter().enumerate() {
+        map.insert(key, i);
+    }
+
+    // A wrong hash would probe the wrong buckets, so every lookup succeeding
+    // means hash_one returns exactly the hash the map computes. Borrowed
+    // forms of the key hash the same, as Borrow requires.
+    for (i, key) in keys().iter().enumerate() {
+        let hash = state.hash_one(key);
+        assert_eq!(hash, state.hash_one(key.as_str()));
+        let found = map.raw_entry().from_key_hashed_nocheck(hash, key.as_str());
+        assert_eq!(found, Some((key, &i)));
+    }
+
+    let wrong_hits = keys()
+        .iter()
+        .filter(|key| {
+            let hash = state.hash_one(key) ^ 0xdead_beef;
+            map.raw_entry()
+                .from_key_hashed_nocheck(hash, key.as_str())
+                .is_some()
+        })
+        .count();
+    assert!(wrong_hits < keys().len() / 2);
+}
+
+#[test]
+fn prehash_matches_map_hash() {
+    assert_matches_map_hash(fast::RandomState::default());
+    assert_matches_map_hash(fast::FixedState::with_seed(42));
+    assert_matches_map_hash(quality::RandomState::default());
+    assert_matches_map_hash(quality::FixedState::with_seed(42));
+}
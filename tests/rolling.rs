@@ -0,0 +1,55 @@
+use foldhash::rolling::RollingHash;
+
+fn fresh_hash(window: &[u8], seed: u64) -> u64 {
+    let mut hasher = RollingHash::new(window.len(), seed);
+    let mut hash = 0;
+    for &b in window {
+        hash = hasher.push(b);
+    }
+    hash
+}
+
+#[test]
+fn rolled_hash_matches_fresh_hash() {
+    let data: Vec<u8> = (0..2000u32).map(|i| (i * i % 251) as u8).collect();
+    for seed in [0, 1, 42, u64::MAX] {
+        for window in [1, 2, 7, 16, 64, 1000] {
+            let mut rolling = RollingHash::new(window, seed);
+            for &b in &data[..window] {
+                rolling.push(b);
+            }
+            assert_eq!(rolling.hash(), fresh_hash(&data[..window], seed));
+            for i in window..data.len() {
+                let hash = rolling.roll(data[i - window], data[i]);
+                assert_eq!(hash, fresh_hash(&data[i + 1 - window..=i], seed));
+            }
+        }
+    }
+}
+
+#[test]
+fn rolling_hash_separates_windows() {
+    // Windows differing in a single byte at any position must not collide,
+    // including in leading zeros.
+    for seed in [0, 42] {
+        let base = [0u8; 16];
+        let expected = fresh_hash(&base, seed);
+        for pos in 0..base.len() {
+            for b in 1..=255 {
+                let mut window = base;
+                window[pos] = b;
+                assert_ne!(fresh_hash(&window, seed), expected);
+            }
+        }
+        assert_ne!(fresh_hash(b"ab", seed), fresh_hash(b"ba", seed));
+        assert_ne!(fresh_hash(b"abc", seed), fresh_hash(b"abc", seed + 1));
+    }
+}
+
+#[test]
+#[should_panic]
+fn roll_requires_a_full_window() {
+    let mut rolling = RollingHash::new(4, 0);
+    rolling.push(1);
+    rolling.roll(1, 2);
+}
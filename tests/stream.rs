@@ -0,0 +1,72 @@
+use std::hash::{BuildHasher, Hasher};
+
+use foldhash::fast::RandomState;
+use rand::prelude::*;
+
+/// Splits `data` at `num_splits` random points, possibly creating empty parts.
+fn random_parts<'a>(data: &'a [u8], num_splits: usize, rng: &mut impl Rng) -> Vec<&'a [u8]> {
+    let mut points: Vec<usize> = (0..num_splits)
+        .map(|_| rng.gen_range(0..=data.len()))
+        .collect();
+    points.sort_unstable();
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for point in points {
+        parts.push(&data[start..point]);
+        start = point;
+    }
+    parts.push(&data[start..]);
+    parts
+}
+
+#[test]
+fn hash_concat_ignores_split_points() {
+    let mut rng = StdRng::seed_from_u64(0x123456789abcdef);
+    let state = RandomState::default();
+    let data: Vec<u8> = (0..1500).map(|_| rng.gen()).collect();
+    for len in (0..600).chain([1023, 1024, 1025, 1500]) {
+        let data = &data[..len];
+        let expected = state.hash_one(data);
+        for num_splits in [0, 1, 2, 5, 20] {
+            let parts = random_parts(data, num_splits, &mut rng);
+            assert_eq!(
+                state.hash_concat(&parts),
+                expected,
+                "len {len}, parts {parts:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn write_iter_matches_write() {
+    let mut rng = StdRng::seed_from_u64(0x123456789abcdef);
+    let state = RandomState::default();
+    let data: Vec<u8> = (0..1500).map(|_| rng.gen()).collect();
+    for len in (0..600).chain([1023, 1024, 1025, 1500]) {
+        let mut a = state.build_hasher();
+        let mut b = state.build_hasher();
+        a.write_u32(7);
+        b.write_u32(7);
+        a.write_iter(data[..len].iter().copied());
+        b.write(&data[..len]);
+        assert_eq!(a.finish(), b.finish(), "len {len}");
+    }
+}
+
+#[test]
+fn absorb_digest_is_ordered_with_integer_writes() {
+    let state = foldhash::fast::FixedState::with_seed(42);
+    let hash = |digest_first: bool| {
+        let mut hasher = state.build_hasher();
+        if digest_first {
+            hasher.absorb_digest(7);
+            hasher.write_u32(1);
+        } else {
+            hasher.write_u32(1);
+            hasher.absorb_digest(7);
+        }
+        hasher.finish()
+    };
+    assert_ne!(hash(true), hash(false));
+}
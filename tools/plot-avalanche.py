@@ -21,3 +21,8 @@ plot_avalanche("foldhash-quality")
 plot_avalanche("fxhash")
 plot_avalanche("ahash")
 plot_avalanche("siphash")
+plot_avalanche("seq-foldhash-fast")
+plot_avalanche("seq-foldhash-quality")
+plot_avalanche("seq-fxhash")
+plot_avalanche("seq-ahash")
+plot_avalanche("seq-siphash")